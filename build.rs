@@ -0,0 +1,343 @@
+//! Generates the solution registry from the files in `src/aoc{year}/day{day}.rs`: the
+//! `DAYS` and `run_solution_for_day` of every year, and the `YEARS` table of `lib.rs`.
+//! Also generates the tests of `tests.rs`, one per part and input with an expected answer.
+//!
+//! The `mod` declarations stay in the sources so that rustfmt can find every file, a
+//! solution file that is not declared fails the build instead of being silently ignored.
+//! Years whose cargo feature is disabled are left out of `YEARS` and of the tests.
+
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+#[path = "src/calendar.rs"]
+mod calendar;
+
+struct Year {
+    year: u32,
+    dir: PathBuf,
+    days: Vec<u32>,
+    /// Whether the `aoc{year}` feature is enabled
+    enabled: bool,
+}
+
+fn parse_number(name: &str, prefix: &str, suffix: &str) -> Option<u32> {
+    let number = name.strip_prefix(prefix)?.strip_suffix(suffix)?;
+    if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    number.parse().ok()
+}
+
+fn file_names(dir: &Path) -> Vec<String> {
+    std::fs::read_dir(dir)
+        .unwrap_or_else(|err| panic!("cannot read {}: {err}", dir.display()))
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .collect()
+}
+
+/// Panics unless `file` contains the module declaration `declaration`, public or not
+fn check_declared(file: &Path, declaration: &str) {
+    let source = std::fs::read_to_string(file)
+        .unwrap_or_else(|err| panic!("cannot read {}: {err}", file.display()));
+    let private = declaration.trim_start_matches("pub ");
+    if !source
+        .lines()
+        .any(|line| line.trim().trim_start_matches("pub ") == private)
+    {
+        panic!(
+            "{private:?} is missing from {}, add `{declaration}` to it",
+            file.display()
+        );
+    }
+}
+
+fn find_years(src: &Path) -> Vec<Year> {
+    let mut years: Vec<Year> = file_names(src)
+        .into_iter()
+        .filter_map(|name| {
+            let year = parse_number(&name, "aoc", "")?;
+            let dir = src.join(&name);
+            dir.join("mod.rs").is_file().then_some(Year {
+                year,
+                dir,
+                days: Vec::new(),
+                enabled: std::env::var_os(format!("CARGO_FEATURE_AOC{year}")).is_some(),
+            })
+        })
+        .collect();
+    years.sort_by_key(|year| year.year);
+
+    for year in &mut years {
+        year.days = file_names(&year.dir)
+            .iter()
+            .filter_map(|name| parse_number(name, "day", ".rs"))
+            .filter(|&day| (1..=calendar::days_in(year.year)).contains(&day))
+            .collect();
+        year.days.sort_unstable();
+    }
+    years
+}
+
+fn year_module(year: &Year) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "/// Days of the year that have a solution\npub const DAYS: [u32; {}] = {:?};",
+        year.days.len(),
+        year.days
+    )
+    .unwrap();
+
+    out.push_str(
+        "\npub fn run_solution_for_day(
+    day: u32,
+    input: &DayInput,
+    config: &RunConfig,
+) -> Option<Result<DayReport, Box<dyn std::error::Error>>> {
+    let report = match day {\n",
+    );
+    for day in &year.days {
+        writeln!(
+            out,
+            "        {day} => run::<Aoc{}, Day{day}>(input, config),",
+            year.year
+        )
+        .unwrap();
+    }
+    out.push_str("        _ => return None,\n    };\n    Some(report)\n}\n");
+
+    out.push_str(
+        "\n/// Whether the solution of a day has a second part, `false` without a solution
+pub fn has_part2(day: u32) -> bool {
+    match day {\n",
+    );
+    for day in &year.days {
+        writeln!(
+            out,
+            "        {day} => crate::helpers::has_answer::<<Aoc{} as crate::traits::Solution<Day{day}>>::Part2Output>(),",
+            year.year
+        )
+        .unwrap();
+    }
+    out.push_str("        _ => false,\n    }\n}\n");
+    out
+}
+
+/// Panics unless the `[features]` of `manifest` declare the feature of `year`
+fn check_feature(manifest: &Path, year: u32) {
+    let source = std::fs::read_to_string(manifest)
+        .unwrap_or_else(|err| panic!("cannot read {}: {err}", manifest.display()));
+    let feature = format!("aoc{year}");
+    if !source
+        .lines()
+        .any(|line| line.split('=').next().map(str::trim) == Some(feature.as_str()))
+    {
+        panic!(
+            "feature {feature:?} is missing from {}, add `{feature} = []` to its [features]",
+            manifest.display()
+        );
+    }
+}
+
+fn years_table(years: &[Year]) -> String {
+    let (enabled, disabled): (Vec<&Year>, Vec<&Year>) = years.iter().partition(|y| y.enabled);
+    let mut out = String::new();
+    writeln!(
+        out,
+        "/// Every year with solutions, with its days and runner\npub const YEARS: [(u32, &[u32], YearRunner); {}] = [",
+        enabled.len()
+    )
+    .unwrap();
+    for Year { year, .. } in &enabled {
+        writeln!(
+            out,
+            "    ({year}, &aoc{year}::DAYS, aoc{year}::run_solution_for_day),"
+        )
+        .unwrap();
+    }
+    out.push_str("];\n");
+
+    out.push_str(
+        "\n/// Whether the solution of a day has a second part, `false` without a solution
+pub fn has_part2(year: u32, day: u32) -> bool {
+    match year {\n",
+    );
+    for Year { year, .. } in &enabled {
+        writeln!(out, "        {year} => aoc{year}::has_part2(day),").unwrap();
+    }
+    out.push_str("        _ => false,\n    }\n}\n");
+
+    let disabled: Vec<u32> = disabled.iter().map(|y| y.year).collect();
+    writeln!(
+        out,
+        "\n/// Years with solutions whose feature is disabled\npub const DISABLED_YEARS: [u32; {}] = {disabled:?};",
+        disabled.len()
+    )
+    .unwrap();
+    out
+}
+
+/// Expected answers of both parts in a JSON object, like `{"part1": "42"}`
+fn part_answers(value: &serde_json::Value) -> [bool; 2] {
+    ["part1", "part2"].map(|part| value.get(part).is_some_and(|answer| !answer.is_null()))
+}
+
+fn read_json(path: &Path) -> Option<serde_json::Value> {
+    let content = std::fs::read_to_string(path).ok()?;
+    Some(
+        serde_json::from_str(&content)
+            .unwrap_or_else(|err| panic!("cannot load {}: {err}", path.display())),
+    )
+}
+
+/// Example inputs of a day, with their labels, like `inputs::example_paths`
+fn examples(inputs: &Path, day: u32) -> Vec<(String, PathBuf)> {
+    let prefix = format!("day{day}_test");
+    let mut numbered: Vec<(u32, String)> = if inputs.is_dir() {
+        file_names(inputs)
+            .into_iter()
+            .filter_map(|name| {
+                let number = name.strip_prefix(&prefix)?.strip_suffix(".txt")?;
+                let number = number.strip_prefix('_').unwrap_or(number);
+                let number = if number.is_empty() {
+                    0
+                } else {
+                    number.parse().ok()?
+                };
+                Some((number, name))
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    numbered.sort();
+
+    let mut examples: Vec<(String, PathBuf)> = numbered
+        .into_iter()
+        .map(|(_, name)| {
+            let label = name.trim_start_matches(&format!("day{day}_"));
+            (
+                label.trim_end_matches(".txt").to_owned(),
+                inputs.join(&name),
+            )
+        })
+        .collect();
+
+    let test_dir = inputs.join(&prefix);
+    if test_dir.is_dir() {
+        let mut names: Vec<String> = file_names(&test_dir)
+            .into_iter()
+            .filter(|name| name.ends_with(".txt"))
+            .collect();
+        names.sort();
+        for name in names {
+            let label = format!("test/{}", name.trim_end_matches(".txt"));
+            examples.push((label, test_dir.join(name)));
+        }
+    }
+    examples
+}
+
+/// Name of a test function for `label`
+fn test_name(part: u32, label: &str) -> String {
+    let label: String = label
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("part{part}_{label}")
+}
+
+fn tests_module(root: &Path, years: &[Year]) -> String {
+    let mut out = String::new();
+    for Year { year, days, .. } in years.iter().filter(|y| y.enabled) {
+        let inputs = root.join(format!("inputs/{year}"));
+        let results = read_json(&root.join(format!("results/{year}.json")));
+        writeln!(out, "mod aoc{year} {{").unwrap();
+        for day in days {
+            let real_answers = results
+                .as_ref()
+                .and_then(|results| results.as_array())
+                .and_then(|results| results.iter().find(|r| r["day"] == *day))
+                .map_or([false; 2], part_answers);
+            let has_input = inputs.join(format!("day{day}.txt")).is_file();
+
+            writeln!(out, "    mod day{day} {{").unwrap();
+            for part in [1, 2] {
+                if !real_answers[part as usize - 1] {
+                    continue;
+                }
+                let ignore = if has_input {
+                    String::new()
+                } else {
+                    format!("        #[ignore = \"inputs/{year}/day{day}.txt is missing\"]\n")
+                };
+                writeln!(
+                    out,
+                    "        #[test]\n{ignore}        fn part{part}() {{\n            crate::tests::check_real({year}, {day}, {part});\n        }}"
+                )
+                .unwrap();
+            }
+            for (label, path) in examples(&inputs, *day) {
+                let answers = read_json(&path.with_extension("json"))
+                    .as_ref()
+                    .map_or([false; 2], part_answers);
+                for part in [1, 2] {
+                    if !answers[part as usize - 1] {
+                        continue;
+                    }
+                    writeln!(
+                        out,
+                        "        #[test]\n        fn {}() {{\n            crate::tests::check_example({year}, {day}, {part}, {:?}, {label:?});\n        }}",
+                        test_name(part, &label),
+                        path.display().to_string(),
+                    )
+                    .unwrap();
+                }
+            }
+            writeln!(out, "    }}").unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+    }
+    out
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    let src = manifest_dir.join("src");
+    println!("cargo:rerun-if-changed={}", src.display());
+    println!(
+        "cargo:rerun-if-changed={}",
+        manifest_dir.join("Cargo.toml").display()
+    );
+    // the tests depend on which inputs and answers there are
+    println!(
+        "cargo:rerun-if-changed={}",
+        manifest_dir.join("inputs").display()
+    );
+    println!(
+        "cargo:rerun-if-changed={}",
+        manifest_dir.join("results").display()
+    );
+
+    let years = find_years(&src);
+    for year in &years {
+        check_feature(&manifest_dir.join("Cargo.toml"), year.year);
+        check_declared(&src.join("lib.rs"), &format!("pub mod aoc{};", year.year));
+        for day in &year.days {
+            check_declared(&year.dir.join("mod.rs"), &format!("pub mod day{day};"));
+        }
+
+        std::fs::write(
+            out_dir.join(format!("aoc{}.rs", year.year)),
+            year_module(year),
+        )
+        .unwrap();
+    }
+    std::fs::write(out_dir.join("years.rs"), years_table(&years)).unwrap();
+    std::fs::write(
+        out_dir.join("tests.rs"),
+        tests_module(&manifest_dir, &years),
+    )
+    .unwrap();
+}
@@ -0,0 +1,154 @@
+use std::fmt;
+
+/// Allocations made by a phase, on the thread that ran it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, reallocations included
+    pub count: u64,
+    /// Bytes requested by those allocations
+    pub bytes: u64,
+    /// Highest number of bytes allocated by the phase and not freed yet
+    pub peak: u64,
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.count,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+/// Runs `f` and counts its allocations. Only the allocations of the calling thread are
+/// counted, and only with the `alloc-stats` feature, `None` without it.
+#[cfg(not(feature = "alloc-stats"))]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    (f(), None)
+}
+
+#[cfg(feature = "alloc-stats")]
+pub use counting::measure;
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    use super::AllocStats;
+
+    /// Counters of the current thread
+    struct Counters {
+        count: Cell<u64>,
+        bytes: Cell<u64>,
+        /// Bytes allocated minus bytes freed, negative when freeing older allocations
+        live: Cell<i64>,
+        peak: Cell<i64>,
+    }
+
+    thread_local! {
+        // const and without destructor, so the allocator can use it at any time
+        static COUNTERS: Counters = const {
+            Counters {
+                count: Cell::new(0),
+                bytes: Cell::new(0),
+                live: Cell::new(0),
+                peak: Cell::new(0),
+            }
+        };
+    }
+
+    fn record(allocated: usize, freed: usize) {
+        let _ = COUNTERS.try_with(|counters| {
+            if allocated > 0 {
+                counters.count.set(counters.count.get() + 1);
+                counters.bytes.set(counters.bytes.get() + allocated as u64);
+            }
+            let live = counters.live.get() + allocated as i64 - freed as i64;
+            counters.live.set(live);
+            counters.peak.set(counters.peak.get().max(live));
+        });
+    }
+
+    /// The system allocator, counting the allocations of each thread
+    struct Counting;
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record(layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record(layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                record(new_size, layout.size());
+            }
+            new_ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            record(0, layout.size());
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+        let outer = COUNTERS.with(|counters| {
+            (
+                counters.count.replace(0),
+                counters.bytes.replace(0),
+                counters.live.replace(0),
+                counters.peak.replace(0),
+            )
+        });
+        let output = f();
+
+        let stats = COUNTERS.with(|counters| {
+            let (count, bytes, live, peak) = outer;
+            let stats = AllocStats {
+                count: counters.count.get(),
+                bytes: counters.bytes.get(),
+                peak: counters.peak.get().max(0) as u64,
+            };
+            // an enclosing measure still sees what happened in this one
+            counters.count.set(count + stats.count);
+            counters.bytes.set(bytes + stats.bytes);
+            counters.peak.set(peak.max(live + counters.peak.get()));
+            counters.live.set(live + counters.live.get());
+            stats
+        });
+        (output, Some(stats))
+    }
+}
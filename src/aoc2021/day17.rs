@@ -114,6 +114,10 @@ fn is_valid_vx(vx: i32, target: &RangeInclusive<i32>) -> bool {
         if target.contains(&current) {
             return true;
         }
+        // the probe stopped before reaching the target
+        if vx == 0 {
+            break;
+        }
         current += vx;
         vx -= 1;
     }
@@ -1,8 +1,9 @@
 use crate::aoc2022::Aoc2022;
 use crate::grid::Grid;
+use crate::parse::ParseError;
 use crate::traits::days::Day12;
-use crate::traits::ParseInput;
 use crate::traits::Solution;
+use crate::traits::TryParseInput;
 
 pub struct Input {
     start: (usize, usize),
@@ -10,11 +11,11 @@ pub struct Input {
     grid: Grid<u8>,
 }
 
-impl ParseInput<Day12> for Aoc2022 {
+impl TryParseInput<Day12> for Aoc2022 {
     type Parsed = Input;
 
-    fn parse_input(input: &str) -> Self::Parsed {
-        let grid = Grid::parse(input, |c| c as u8);
+    fn try_parse_input(input: &str, _params: &()) -> Result<Self::Parsed, ParseError> {
+        let grid = Grid::parse(input, |c| Some(c as u8))?;
         let mut start = (0, 0);
         let mut end = (0, 0);
         for (x, y, val) in grid.iter() {
@@ -24,7 +25,7 @@ impl ParseInput<Day12> for Aoc2022 {
                 _ => {}
             }
         }
-        Input { start, end, grid }
+        Ok(Input { start, end, grid })
     }
 }
 
@@ -1,11 +1,13 @@
 use std::ops::RangeInclusive;
 
 use regex::Regex;
+use serde::Deserialize;
 
 use crate::aoc2022::Aoc2022;
+use crate::parse::{self, ParseError};
 use crate::traits::days::Day15;
-use crate::traits::ParseInput;
 use crate::traits::Solution;
+use crate::traits::TryParseInput;
 
 #[derive(Debug, Default)]
 pub struct Sensor {
@@ -31,27 +33,57 @@ impl Sensor {
     }
 }
 
-impl ParseInput<Day15> for Aoc2022 {
-    type Parsed = Vec<Sensor>;
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct Params {
+    /// Row scanned in part 1
+    row: i32,
+    /// Part 2 searches for the beacon in `0..=search_max` on both axes
+    search_max: i32,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            row: 2000000,
+            search_max: 4000000,
+        }
+    }
+}
 
-    fn parse_input(input: &str) -> Self::Parsed {
+#[derive(Debug)]
+pub struct Input {
+    sensors: Vec<Sensor>,
+    params: Params,
+}
+
+impl TryParseInput<Day15> for Aoc2022 {
+    type Parsed = Input;
+    type Params = Params;
+
+    fn try_parse_input(input: &str, params: &Params) -> Result<Self::Parsed, ParseError> {
         let line_re = Regex::new(
             r"Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)",
         )
         .unwrap();
 
-        input
-            .lines()
-            .map(str::trim)
+        let sensors = parse::lines(input)
             .map(|line| {
-                let captures = line_re.captures(line).unwrap();
-                let sx = captures.get(1).unwrap().as_str().parse().unwrap();
-                let sy = captures.get(2).unwrap().as_str().parse().unwrap();
-                let bx = captures.get(3).unwrap().as_str().parse().unwrap();
-                let by = captures.get(4).unwrap().as_str().parse().unwrap();
-                Sensor::new((sx, sy), (bx, by))
+                let captures = line_re.captures(line.text).ok_or_else(|| {
+                    line.error(line.text, "expected a sensor and its closest beacon")
+                })?;
+                let sx = line.parse(&captures[1])?;
+                let sy = line.parse(&captures[2])?;
+                let bx = line.parse(&captures[3])?;
+                let by = line.parse(&captures[4])?;
+                Ok(Sensor::new((sx, sy), (bx, by)))
             })
-            .collect()
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Input {
+            sensors,
+            params: *params,
+        })
     }
 }
 
@@ -59,8 +91,9 @@ impl Solution<Day15> for Aoc2022 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn part1(input: &Vec<Sensor>) -> usize {
-        let y = 2000000i32; // 10 for test input
+    fn part1(input: &Input) -> usize {
+        let y = input.params.row;
+        let input = &input.sensors;
         let mut ranges = Vec::with_capacity(input.len());
         let mut sensor_and_beacons = Vec::with_capacity(input.len() * 2);
 
@@ -109,9 +142,9 @@ impl Solution<Day15> for Aoc2022 {
         count
     }
 
-    fn part2(input: &Vec<Sensor>) -> usize {
-        let meta_range = 0..=4000000;
-        // let meta_range = 0..=20;
+    fn part2(input: &Input) -> usize {
+        let meta_range = 0..=input.params.search_max;
+        let input = &input.sensors;
 
         let mut lines = Vec::with_capacity(input.len() * 4);
         for sensor in input {
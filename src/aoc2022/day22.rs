@@ -1,10 +1,13 @@
 use std::collections::HashMap;
 
+use serde::Deserialize;
+
 use crate::aoc2022::Aoc2022;
 use crate::grid::Grid;
+use crate::parse::ParseError;
 use crate::traits::days::Day22;
-use crate::traits::ParseInput;
 use crate::traits::Solution;
+use crate::traits::TryParseInput;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Cell {
@@ -19,16 +22,35 @@ pub enum Instruction {
     Right,
 }
 
+/// How the faces of the cube are laid out on the map
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CubeLayout {
+    /// The layout of the real inputs
+    #[default]
+    Input,
+    /// The layout of the example
+    Example,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(default)]
+pub struct Params {
+    layout: CubeLayout,
+}
+
 #[derive(Debug)]
 pub struct Input {
     grid: Grid<Option<Cell>>,
     instructions: Vec<Instruction>,
+    layout: CubeLayout,
 }
 
-impl ParseInput<Day22> for Aoc2022 {
+impl TryParseInput<Day22> for Aoc2022 {
     type Parsed = Input;
+    type Params = Params;
 
-    fn parse_input(input: &str) -> Self::Parsed {
+    fn try_parse_input(input: &str, params: &Params) -> Result<Self::Parsed, ParseError> {
         let mut predata = Vec::new();
         let mut width = 0;
         let mut height = 0;
@@ -101,7 +123,11 @@ impl ParseInput<Day22> for Aoc2022 {
             height,
         };
 
-        Input { grid, instructions }
+        Ok(Input {
+            grid,
+            instructions,
+            layout: params.layout,
+        })
     }
 }
 
@@ -142,8 +168,10 @@ impl Solution<Day22> for Aoc2022 {
     }
 
     fn part2(input: &Input) -> usize {
-        // let map = [[0, 0, 1, 0], [2, 3, 4, 0], [0, 0, 5, 6]];
-        let map = [[0, 1, 2], [0, 3, 0], [4, 5, 0], [6, 0, 0]];
+        let map: &[&[usize]] = match input.layout {
+            CubeLayout::Input => &[&[0, 1, 2], &[0, 3, 0], &[4, 5, 0], &[6, 0, 0]],
+            CubeLayout::Example => &[&[0, 0, 1, 0], &[2, 3, 4, 0], &[0, 0, 5, 6]],
+        };
 
         let subheight = input.grid.height / map.len();
         let subwidth = input.grid.width / map[0].len();
@@ -173,6 +201,10 @@ impl Solution<Day22> for Aoc2022 {
             dy: 0,
         };
         let deltas = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+        let edges = match input.layout {
+            CubeLayout::Input => input_edges(subwidth),
+            CubeLayout::Example => example_edges(subwidth),
+        };
 
         for inst in &input.instructions {
             let di = deltas
@@ -183,7 +215,7 @@ impl Solution<Day22> for Aoc2022 {
             match inst {
                 Instruction::Move(offset) => {
                     for _ in 0..*offset {
-                        let next_state = compute_next_pos_part2(&subgrids, &state, subwidth);
+                        let next_state = compute_next_pos_part2(&subgrids, &state, &edges);
                         if let Cell::Wall = *subgrids[next_state.g].get(next_state.x, next_state.y)
                         {
                             break;
@@ -208,9 +240,9 @@ impl Solution<Day22> for Aoc2022 {
 
         let mut gx = 0;
         let mut gy = 0;
-        'top: for (y, line) in map.into_iter().enumerate() {
-            for (x, g) in line.into_iter().enumerate() {
-                if g - 1 == state.g {
+        'top: for (y, line) in map.iter().enumerate() {
+            for (x, &g) in line.iter().enumerate() {
+                if g == state.g + 1 {
                     gx = x;
                     gy = y;
                     break 'top;
@@ -278,67 +310,122 @@ struct State {
     dy: isize,
 }
 
-fn compute_next_pos_part2(grids: &[Grid<Cell>], s: &State, width: usize) -> State {
-    let mut dir_map = HashMap::<
-        (usize, (isize, isize)),
-        (
-            usize,
-            (isize, isize),
-            Box<dyn Fn(usize, usize) -> (usize, usize)>,
-        ),
-    >::default();
-    /*
-    test input
-    dir_map.insert((0, (0, 1)), (3, (0, 1), Box::new(|x, _y| (x, 0))));
+type EdgeMap = HashMap<
+    (usize, (isize, isize)),
+    (
+        usize,
+        (isize, isize),
+        Box<dyn Fn(usize, usize) -> (usize, usize)>,
+    ),
+>;
+
+/// Where walking off each edge of each face leads, for the faces of the real inputs
+fn input_edges(width: usize) -> EdgeMap {
+    let mut dir_map = EdgeMap::default();
+    dir_map.insert((0, (0, -1)), (5, (1, 0), Box::new(|x, _y| (0, x))));
     dir_map.insert(
-        (3, (1, 0)),
-        (5, (0, 1), Box::new(|_x, y| (width - y - 1, 0))),
+        (5, (0, -1)),
+        (3, (0, -1), Box::new(move |x, _y| (x, width - 1))),
     );
-    dir_map.insert((5, (-1, 0)), (4, (-1, 0), Box::new(|_x, y| (width - 1, y))));
-    dir_map.insert(
-        (4, (0, 1)),
-        (1, (0, -1), Box::new(|x, _y| (width - x - 1, width - 1))),
-    );
-    dir_map.insert((1, (1, 0)), (2, (1, 0), Box::new(|_x, y| (0, y))));
-    dir_map.insert((2, (0, -1)), (0, (1, 0), Box::new(|x, _y| (0, x))));
-    */
-    dir_map.insert((0, (0, -1)), (5, (1, 0), Box::new(|x, _y| (0, x))));
-    dir_map.insert((5, (0, -1)), (3, (0, -1), Box::new(|x, _y| (x, width - 1))));
     dir_map.insert(
         (3, (-1, 0)),
-        (0, (1, 0), Box::new(|_x, y| (0, width - y - 1))),
+        (0, (1, 0), Box::new(move |_x, y| (0, width - y - 1))),
     );
     dir_map.insert((3, (0, 1)), (5, (0, 1), Box::new(|x, _y| (x, 0))));
     dir_map.insert((5, (-1, 0)), (0, (0, 1), Box::new(|_x, y| (y, 0))));
     dir_map.insert((0, (1, 0)), (1, (1, 0), Box::new(|_x, y| (0, y))));
-    dir_map.insert((1, (-1, 0)), (0, (-1, 0), Box::new(|_x, y| (width - 1, y))));
+    dir_map.insert(
+        (1, (-1, 0)),
+        (0, (-1, 0), Box::new(move |_x, y| (width - 1, y))),
+    );
     dir_map.insert((0, (0, 1)), (2, (0, 1), Box::new(|x, _y| (x, 0))));
-    dir_map.insert((2, (1, 0)), (1, (0, -1), Box::new(|_x, y| (y, width - 1))));
-    dir_map.insert((1, (0, 1)), (2, (-1, 0), Box::new(|x, _y| (width - 1, x))));
+    dir_map.insert(
+        (2, (1, 0)),
+        (1, (0, -1), Box::new(move |_x, y| (y, width - 1))),
+    );
+    dir_map.insert(
+        (1, (0, 1)),
+        (2, (-1, 0), Box::new(move |x, _y| (width - 1, x))),
+    );
     dir_map.insert((2, (0, 1)), (4, (0, 1), Box::new(|x, _y| (x, 0))));
-    dir_map.insert((4, (0, -1)), (2, (0, -1), Box::new(|x, _y| (x, width - 1))));
+    dir_map.insert(
+        (4, (0, -1)),
+        (2, (0, -1), Box::new(move |x, _y| (x, width - 1))),
+    );
     dir_map.insert(
         (4, (1, 0)),
-        (1, (-1, 0), Box::new(|_x, y| (width - 1, width - y - 1))),
+        (
+            1,
+            (-1, 0),
+            Box::new(move |_x, y| (width - 1, width - y - 1)),
+        ),
     );
     dir_map.insert(
         (1, (1, 0)),
-        (4, (-1, 0), Box::new(|_x, y| (width - 1, width - y - 1))),
+        (
+            4,
+            (-1, 0),
+            Box::new(move |_x, y| (width - 1, width - y - 1)),
+        ),
+    );
+    dir_map.insert(
+        (4, (-1, 0)),
+        (3, (-1, 0), Box::new(move |_x, y| (width - 1, y))),
     );
-    dir_map.insert((4, (-1, 0)), (3, (-1, 0), Box::new(|_x, y| (width - 1, y))));
     dir_map.insert((3, (1, 0)), (4, (1, 0), Box::new(|_x, y| (0, y))));
-    dir_map.insert((1, (0, -1)), (5, (0, -1), Box::new(|x, _y| (x, width - 1))));
+    dir_map.insert(
+        (1, (0, -1)),
+        (5, (0, -1), Box::new(move |x, _y| (x, width - 1))),
+    );
     dir_map.insert((5, (0, 1)), (1, (0, 1), Box::new(|x, _y| (x, 0))));
     dir_map.insert(
         (0, (-1, 0)),
-        (3, (1, 0), Box::new(|_x, y| (0, width - 1 - y))),
+        (3, (1, 0), Box::new(move |_x, y| (0, width - 1 - y))),
+    );
+    dir_map.insert(
+        (5, (1, 0)),
+        (4, (0, -1), Box::new(move |_x, y| (y, width - 1))),
+    );
+    dir_map.insert(
+        (4, (0, 1)),
+        (5, (-1, 0), Box::new(move |x, _y| (width - 1, x))),
     );
-    dir_map.insert((5, (1, 0)), (4, (0, -1), Box::new(|_x, y| (y, width - 1))));
-    dir_map.insert((4, (0, 1)), (5, (-1, 0), Box::new(|x, _y| (width - 1, x))));
     dir_map.insert((3, (0, -1)), (2, (1, 0), Box::new(|x, _y| (0, x))));
-    dir_map.insert((2, (0, -1)), (0, (0, -1), Box::new(|x, _y| (x, width - 1))));
+    dir_map.insert(
+        (2, (0, -1)),
+        (0, (0, -1), Box::new(move |x, _y| (x, width - 1))),
+    );
     dir_map.insert((2, (-1, 0)), (3, (0, 1), Box::new(|_x, y| (y, 0))));
 
+    dir_map
+}
+
+/// Same as `input_edges`, for the edges the example walks through
+fn example_edges(width: usize) -> EdgeMap {
+    let mut dir_map = EdgeMap::default();
+    dir_map.insert((0, (0, 1)), (3, (0, 1), Box::new(|x, _y| (x, 0))));
+    dir_map.insert(
+        (3, (1, 0)),
+        (5, (0, 1), Box::new(move |_x, y| (width - y - 1, 0))),
+    );
+    dir_map.insert(
+        (5, (-1, 0)),
+        (4, (-1, 0), Box::new(move |_x, y| (width - 1, y))),
+    );
+    dir_map.insert(
+        (4, (0, 1)),
+        (
+            1,
+            (0, -1),
+            Box::new(move |x, _y| (width - x - 1, width - 1)),
+        ),
+    );
+    dir_map.insert((1, (1, 0)), (2, (1, 0), Box::new(|_x, y| (0, y))));
+    dir_map.insert((2, (0, -1)), (0, (1, 0), Box::new(|x, _y| (0, x))));
+    dir_map
+}
+
+fn compute_next_pos_part2(grids: &[Grid<Cell>], s: &State, dir_map: &EdgeMap) -> State {
     assert!(s.dx.abs() <= 1);
     assert!(s.dy.abs() <= 1);
 
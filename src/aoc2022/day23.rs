@@ -6,9 +6,10 @@ use itertools::MinMaxResult;
 
 use crate::aoc2022::Aoc2022;
 use crate::grid::Grid;
+use crate::parse::ParseError;
 use crate::traits::days::Day23;
-use crate::traits::ParseInput;
 use crate::traits::Solution;
+use crate::traits::TryParseInput;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Cell {
@@ -16,14 +17,14 @@ pub enum Cell {
     Elf,
 }
 
-impl ParseInput<Day23> for Aoc2022 {
+impl TryParseInput<Day23> for Aoc2022 {
     type Parsed = Grid<Cell>;
 
-    fn parse_input(input: &str) -> Self::Parsed {
+    fn try_parse_input(input: &str, _params: &()) -> Result<Self::Parsed, ParseError> {
         Grid::parse(input, |c| match c {
-            '#' => Cell::Elf,
-            '.' => Cell::Empty,
-            _ => unreachable!(),
+            '#' => Some(Cell::Elf),
+            '.' => Some(Cell::Empty),
+            _ => None,
         })
     }
 }
@@ -2,9 +2,10 @@ use std::collections::HashSet;
 
 use crate::aoc2022::Aoc2022;
 use crate::grid::Grid;
+use crate::parse::ParseError;
 use crate::traits::days::Day24;
-use crate::traits::ParseInput;
 use crate::traits::Solution;
+use crate::traits::TryParseInput;
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
 #[repr(u8)]
@@ -17,18 +18,18 @@ pub enum Cell {
     Wall,
 }
 
-impl ParseInput<Day24> for Aoc2022 {
+impl TryParseInput<Day24> for Aoc2022 {
     type Parsed = Grid<Cell>;
 
-    fn parse_input(input: &str) -> Self::Parsed {
+    fn try_parse_input(input: &str, _params: &()) -> Result<Self::Parsed, ParseError> {
         Grid::parse(input, |c| match c {
-            '.' => Cell::Empty,
-            '#' => Cell::Wall,
-            '<' => Cell::Left,
-            '>' => Cell::Right,
-            '^' => Cell::Up,
-            'v' => Cell::Down,
-            _ => unreachable!(),
+            '.' => Some(Cell::Empty),
+            '#' => Some(Cell::Wall),
+            '<' => Some(Cell::Left),
+            '>' => Some(Cell::Right),
+            '^' => Some(Cell::Up),
+            'v' => Some(Cell::Down),
+            _ => None,
         })
     }
 }
@@ -1,5 +1,6 @@
 use crate::aoc2022::Aoc2022;
 use crate::traits::days::Day25;
+use crate::traits::NoAnswer;
 use crate::traits::ParseInput;
 use crate::traits::Solution;
 
@@ -13,16 +14,16 @@ impl ParseInput<Day25> for Aoc2022 {
 
 impl Solution<Day25> for Aoc2022 {
     type Part1Output = String;
-    type Part2Output = u32;
+    type Part2Output = NoAnswer;
 
     fn part1(input: &Vec<String>) -> String {
         let sum: i64 = input.iter().map(|s| decode_snafu(s)).sum();
         encode_snafu(sum)
     }
 
-    fn part2(_input: &Vec<String>) -> u32 {
+    fn part2(_input: &Vec<String>) -> NoAnswer {
         // no part 2 here, it's day 25 after all
-        0
+        NoAnswer
     }
 }
 
@@ -1,14 +1,15 @@
 use crate::aoc2022::Aoc2022;
 use crate::grid::Grid;
+use crate::parse::ParseError;
 use crate::traits::days::Day8;
-use crate::traits::ParseInput;
 use crate::traits::Solution;
+use crate::traits::TryParseInput;
 
-impl ParseInput<Day8> for Aoc2022 {
+impl TryParseInput<Day8> for Aoc2022 {
     type Parsed = Grid<u8>;
 
-    fn parse_input(input: &str) -> Self::Parsed {
-        Grid::parse(input, |c| c.to_string().parse::<u8>().unwrap())
+    fn try_parse_input(input: &str, _params: &()) -> Result<Self::Parsed, ParseError> {
+        Grid::parse(input, |c| c.to_digit(10).map(|d| d as u8))
     }
 }
 
@@ -1,14 +1,15 @@
 use crate::aoc2023::Aoc2023;
 use crate::grid::{Direction, Grid};
+use crate::parse::ParseError;
 use crate::traits::days::Day10;
-use crate::traits::ParseInput;
 use crate::traits::Solution;
+use crate::traits::TryParseInput;
 
-impl ParseInput<Day10> for Aoc2023 {
+impl TryParseInput<Day10> for Aoc2023 {
     type Parsed = Grid<char>;
 
-    fn parse_input(input: &str) -> Self::Parsed {
-        Grid::parse(input, |c| c)
+    fn try_parse_input(input: &str, _params: &()) -> Result<Self::Parsed, ParseError> {
+        Grid::parse(input, Some)
     }
 }
 
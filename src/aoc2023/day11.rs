@@ -1,17 +1,18 @@
 use crate::aoc2023::Aoc2023;
 use crate::grid::Grid;
+use crate::parse::ParseError;
 use crate::traits::days::Day11;
-use crate::traits::ParseInput;
 use crate::traits::Solution;
+use crate::traits::TryParseInput;
 
-impl ParseInput<Day11> for Aoc2023 {
+impl TryParseInput<Day11> for Aoc2023 {
     type Parsed = Grid<bool>;
 
-    fn parse_input(input: &str) -> Self::Parsed {
+    fn try_parse_input(input: &str, _params: &()) -> Result<Self::Parsed, ParseError> {
         Grid::parse(input, |c| match c {
-            '.' => false,
-            '#' => true,
-            _ => unreachable!(),
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
         })
     }
 }
@@ -1,21 +1,26 @@
 use crate::aoc2023::Aoc2023;
 use crate::grid::Grid;
+use crate::parse::ParseError;
 use crate::traits::days::Day13;
-use crate::traits::ParseInput;
 use crate::traits::Solution;
+use crate::traits::TryParseInput;
 
-impl ParseInput<Day13> for Aoc2023 {
+impl TryParseInput<Day13> for Aoc2023 {
     type Parsed = Vec<Grid<bool>>;
 
-    fn parse_input(input: &str) -> Self::Parsed {
+    fn try_parse_input(input: &str, _params: &()) -> Result<Self::Parsed, ParseError> {
+        let mut lines_before = 0;
         input
             .split("\n\n")
             .map(|group| {
-                Grid::parse(group, |c| match c {
-                    '#' => true,
-                    '.' => false,
-                    _ => unreachable!(),
+                let grid = Grid::parse(group, |c| match c {
+                    '#' => Some(true),
+                    '.' => Some(false),
+                    _ => None,
                 })
+                .map_err(|err| err.after_lines(lines_before));
+                lines_before += group.lines().count() + 1;
+                grid
             })
             .collect()
     }
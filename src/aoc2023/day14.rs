@@ -2,9 +2,10 @@ use std::collections::HashMap;
 
 use crate::aoc2023::Aoc2023;
 use crate::grid::Grid;
+use crate::parse::ParseError;
 use crate::traits::days::Day14;
-use crate::traits::ParseInput;
 use crate::traits::Solution;
+use crate::traits::TryParseInput;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Cell {
@@ -13,15 +14,15 @@ pub enum Cell {
     Empty,
 }
 
-impl ParseInput<Day14> for Aoc2023 {
+impl TryParseInput<Day14> for Aoc2023 {
     type Parsed = Grid<Cell>;
 
-    fn parse_input(input: &str) -> Self::Parsed {
+    fn try_parse_input(input: &str, _params: &()) -> Result<Self::Parsed, ParseError> {
         Grid::parse(input, |c| match c {
-            '#' => Cell::Cube,
-            'O' => Cell::Round,
-            '.' => Cell::Empty,
-            _ => unreachable!(),
+            '#' => Some(Cell::Cube),
+            'O' => Some(Cell::Round),
+            '.' => Some(Cell::Empty),
+            _ => None,
         })
     }
 }
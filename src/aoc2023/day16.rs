@@ -1,9 +1,10 @@
 use crate::aoc2023::Aoc2023;
 use crate::grid::Direction;
 use crate::grid::Grid;
+use crate::parse::ParseError;
 use crate::traits::days::Day16;
-use crate::traits::ParseInput;
 use crate::traits::Solution;
+use crate::traits::TryParseInput;
 
 #[derive(Debug, Clone, Copy)]
 pub enum Cell {
@@ -14,17 +15,17 @@ pub enum Cell {
     Dash,
 }
 
-impl ParseInput<Day16> for Aoc2023 {
+impl TryParseInput<Day16> for Aoc2023 {
     type Parsed = Grid<Cell>;
 
-    fn parse_input(input: &str) -> Self::Parsed {
+    fn try_parse_input(input: &str, _params: &()) -> Result<Self::Parsed, ParseError> {
         Grid::parse(input, |c| match c {
-            '.' => Cell::Empty,
-            '/' => Cell::Slash,
-            '\\' => Cell::AntiSlash,
-            '|' => Cell::Pipe,
-            '-' => Cell::Dash,
-            _ => unreachable!(),
+            '.' => Some(Cell::Empty),
+            '/' => Some(Cell::Slash),
+            '\\' => Some(Cell::AntiSlash),
+            '|' => Some(Cell::Pipe),
+            '-' => Some(Cell::Dash),
+            _ => None,
         })
     }
 }
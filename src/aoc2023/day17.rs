@@ -3,15 +3,16 @@ use std::collections::BinaryHeap;
 use crate::aoc2023::Aoc2023;
 use crate::grid::Direction;
 use crate::grid::Grid;
+use crate::parse::ParseError;
 use crate::traits::days::Day17;
-use crate::traits::ParseInput;
 use crate::traits::Solution;
+use crate::traits::TryParseInput;
 
-impl ParseInput<Day17> for Aoc2023 {
+impl TryParseInput<Day17> for Aoc2023 {
     type Parsed = Grid<u32>;
 
-    fn parse_input(input: &str) -> Self::Parsed {
-        Grid::parse(input, |c| c.to_digit(10).unwrap())
+    fn try_parse_input(input: &str, _params: &()) -> Result<Self::Parsed, ParseError> {
+        Grid::parse(input, |c| c.to_digit(10))
     }
 }
 
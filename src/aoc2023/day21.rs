@@ -1,16 +1,47 @@
 use std::collections::HashSet;
 
+use serde::Deserialize;
+
 use crate::aoc2023::Aoc2023;
 use crate::grid::Grid;
+use crate::parse::ParseError;
 use crate::traits::days::Day21;
-use crate::traits::ParseInput;
 use crate::traits::Solution;
+use crate::traits::TryParseInput;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct Params {
+    /// Steps taken in part 1
+    steps: usize,
+    /// Steps taken on the infinite map of part 2
+    infinite_steps: usize,
+}
 
-impl ParseInput<Day21> for Aoc2023 {
-    type Parsed = Grid<char>;
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            steps: 64,
+            infinite_steps: 26501365,
+        }
+    }
+}
 
-    fn parse_input(input: &str) -> Self::Parsed {
-        Grid::parse(input, |c| c)
+#[derive(Debug)]
+pub struct Input {
+    grid: Grid<char>,
+    params: Params,
+}
+
+impl TryParseInput<Day21> for Aoc2023 {
+    type Parsed = Input;
+    type Params = Params;
+
+    fn try_parse_input(input: &str, params: &Params) -> Result<Self::Parsed, ParseError> {
+        Ok(Input {
+            grid: Grid::parse(input, Some)?,
+            params: *params,
+        })
     }
 }
 
@@ -18,7 +49,9 @@ impl Solution<Day21> for Aoc2023 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn part1(input: &Grid<char>) -> usize {
+    fn part1(input: &Input) -> usize {
+        let steps = input.params.steps;
+        let input = &input.grid;
         let mut start = (0, 0);
 
         for (x, y, value) in input.iter() {
@@ -30,7 +63,7 @@ impl Solution<Day21> for Aoc2023 {
         let mut open_set = HashSet::new();
         open_set.insert(start);
 
-        for _ in 0..64 {
+        for _ in 0..steps {
             let mut new_open_set = HashSet::new();
 
             for (x, y) in open_set {
@@ -47,7 +80,9 @@ impl Solution<Day21> for Aoc2023 {
         open_set.len()
     }
 
-    fn part2(input: &Grid<char>) -> usize {
+    fn part2(input: &Input) -> usize {
+        let steps = input.params.infinite_steps;
+        let input = &input.grid;
         let mut start = (0, 0);
 
         for (x, y, value) in input.iter() {
@@ -101,12 +136,16 @@ impl Solution<Day21> for Aoc2023 {
 
             step += 1;
 
+            // done before the growth could be extrapolated
+            if step == steps {
+                return counters.1 as usize;
+            }
             if step >= input.width * 2 && second_derivative.iter().all(|&d| d == 0) {
                 break;
             }
         }
 
-        for i in step..26501365 {
+        for i in step..steps {
             let imod = i % input.width;
             open_sizes[imod] += first_derivative[imod];
             counters = (counters.1, open_sizes[imod] + counters.0);
@@ -1,10 +1,12 @@
 use nalgebra::matrix;
 use nalgebra::vector;
+use serde::Deserialize;
 
 use crate::aoc2023::Aoc2023;
+use crate::parse::ParseError;
 use crate::traits::days::Day24;
-use crate::traits::ParseInput;
 use crate::traits::Solution;
+use crate::traits::TryParseInput;
 
 pub type Vec3 = nalgebra::Vector3<f64>;
 pub type Vec6 = nalgebra::Vector6<f64>;
@@ -23,11 +25,35 @@ impl Ball {
     }
 }
 
-impl ParseInput<Day24> for Aoc2023 {
-    type Parsed = Vec<Ball>;
+/// Part 1 counts the crossings in the `min..=max` square
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct Params {
+    min: f64,
+    max: f64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            min: 200000000000000.0,
+            max: 400000000000000.0,
+        }
+    }
+}
 
-    fn parse_input(input: &str) -> Self::Parsed {
-        input
+#[derive(Debug)]
+pub struct Input {
+    balls: Vec<Ball>,
+    params: Params,
+}
+
+impl TryParseInput<Day24> for Aoc2023 {
+    type Parsed = Input;
+    type Params = Params;
+
+    fn try_parse_input(input: &str, params: &Params) -> Result<Self::Parsed, ParseError> {
+        let balls = input
             .lines()
             .map(|line| {
                 let (pos, speed) = line.split_once(" @ ").unwrap();
@@ -45,7 +71,12 @@ impl ParseInput<Day24> for Aoc2023 {
 
                 Ball { pos, speed }
             })
-            .collect()
+            .collect();
+
+        Ok(Input {
+            balls,
+            params: *params,
+        })
     }
 }
 
@@ -53,10 +84,9 @@ impl Solution<Day24> for Aoc2023 {
     type Part1Output = usize;
     type Part2Output = u64;
 
-    fn part1(input: &Vec<Ball>) -> usize {
-        const MIN: f64 = 200000000000000.0;
-        const MAX: f64 = 400000000000000.0;
-        let bounds = MIN..=MAX;
+    fn part1(input: &Input) -> usize {
+        let bounds = input.params.min..=input.params.max;
+        let input = &input.balls;
 
         let mut counter = 0;
         for (ia, a) in input.iter().enumerate() {
@@ -81,7 +111,8 @@ impl Solution<Day24> for Aoc2023 {
         counter
     }
 
-    fn part2(input: &Vec<Ball>) -> u64 {
+    fn part2(input: &Input) -> u64 {
+        let input = &input.balls;
         assert!(input.len() >= 3);
 
         let mut rhs = Vec6::zeros();
@@ -3,6 +3,7 @@ use rustworkx_core::petgraph::graphmap::UnGraphMap;
 
 use crate::aoc2023::Aoc2023;
 use crate::traits::days::Day25;
+use crate::traits::NoAnswer;
 use crate::traits::ParseInput;
 use crate::traits::Solution;
 
@@ -31,7 +32,7 @@ impl ParseInput<Day25> for Aoc2023 {
 
 impl Solution<Day25> for Aoc2023 {
     type Part1Output = usize;
-    type Part2Output = usize;
+    type Part2Output = NoAnswer;
 
     fn part1(input: &Vec<Edges>) -> usize {
         let mut graph = UnGraphMap::new();
@@ -48,7 +49,7 @@ impl Solution<Day25> for Aoc2023 {
         group.len() * (graph.node_count() - group.len())
     }
 
-    fn part2(_: &Vec<Edges>) -> usize {
-        0
+    fn part2(_: &Vec<Edges>) -> NoAnswer {
+        NoAnswer
     }
 }
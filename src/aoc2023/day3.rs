@@ -1,14 +1,15 @@
 use crate::aoc2023::Aoc2023;
 use crate::grid::Grid;
+use crate::parse::ParseError;
 use crate::traits::days::Day3;
-use crate::traits::ParseInput;
 use crate::traits::Solution;
+use crate::traits::TryParseInput;
 
-impl ParseInput<Day3> for Aoc2023 {
+impl TryParseInput<Day3> for Aoc2023 {
     type Parsed = Grid<u8>;
 
-    fn parse_input(input: &str) -> Self::Parsed {
-        Grid::parse(input, |c| c as u8)
+    fn try_parse_input(input: &str, _params: &()) -> Result<Self::Parsed, ParseError> {
+        Grid::parse(input, |c| Some(c as u8))
     }
 }
 
@@ -1,31 +1,36 @@
 use crate::aoc2024::Aoc2024;
+use crate::parse::{self, ParseError};
 use crate::traits::days::Day1;
-use crate::traits::ParseInput;
 use crate::traits::Solution;
+use crate::traits::TryParseInput;
 
-impl ParseInput<Day1> for Aoc2024 {
+impl TryParseInput<Day1> for Aoc2024 {
     type Parsed = (Vec<u32>, Vec<u32>);
 
-    fn parse_input(input: &str) -> Self::Parsed {
+    fn try_parse_input(input: &str, _params: &()) -> Result<Self::Parsed, ParseError> {
         let mut lefts = Vec::new();
         let mut rights = Vec::new();
 
-        for line in input.lines() {
-            let line = line.trim();
+        for line in parse::lines(input) {
+            let text = line.text.trim();
 
-            if line.is_empty() {
+            if text.is_empty() {
                 continue;
             }
 
-            let mut parts = line.split_ascii_whitespace();
-            let left = parts.next().unwrap().parse().unwrap();
-            let right = parts.next().unwrap().parse().unwrap();
+            let mut parts = text.split_ascii_whitespace();
+            let left = parts
+                .next()
+                .ok_or_else(|| line.missing("the left number"))?;
+            let right = parts
+                .next()
+                .ok_or_else(|| line.missing("the right number"))?;
 
-            lefts.push(left);
-            rights.push(right);
+            lefts.push(line.parse(left)?);
+            rights.push(line.parse(right)?);
         }
 
-        (lefts, rights)
+        Ok((lefts, rights))
     }
 }
 
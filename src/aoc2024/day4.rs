@@ -2,15 +2,16 @@ use itertools::Itertools;
 
 use crate::aoc2024::Aoc2024;
 use crate::grid::Grid;
+use crate::parse::ParseError;
 use crate::traits::days::Day4;
-use crate::traits::ParseInput;
 use crate::traits::Solution;
+use crate::traits::TryParseInput;
 
-impl ParseInput<Day4> for Aoc2024 {
+impl TryParseInput<Day4> for Aoc2024 {
     type Parsed = Grid<char>;
 
-    fn parse_input(input: &str) -> Self::Parsed {
-        Grid::parse(input, |c| c)
+    fn try_parse_input(input: &str, _params: &()) -> Result<Self::Parsed, ParseError> {
+        Grid::parse(input, Some)
     }
 }
 
@@ -1,16 +1,17 @@
 use crate::aoc2024::Aoc2024;
 use crate::grid::Grid;
+use crate::parse::ParseError;
 use crate::traits::days::Day6;
-use crate::traits::ParseInput;
 use crate::traits::Solution;
+use crate::traits::TryParseInput;
 
-impl ParseInput<Day6> for Aoc2024 {
+impl TryParseInput<Day6> for Aoc2024 {
     type Parsed = (Grid<bool>, usize, usize);
 
-    fn parse_input(input: &str) -> Self::Parsed {
+    fn try_parse_input(input: &str, _params: &()) -> Result<Self::Parsed, ParseError> {
         let mut sx = 0;
         let mut sy = 0;
-        let grid = Grid::parse(input, |c| c);
+        let grid = Grid::parse(input, |c| matches!(c, '#' | '.' | '^').then_some(c))?;
 
         let mut bgrid = Grid::new(grid.width, grid.height, false);
         grid.iter().for_each(|(x, y, &c)| {
@@ -26,7 +27,7 @@ impl ParseInput<Day6> for Aoc2024 {
             };
             bgrid.set(x, y, val);
         });
-        (bgrid, sx, sy)
+        Ok((bgrid, sx, sy))
     }
 }
 
@@ -0,0 +1,284 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::time::Duration;
+
+use aoc::helpers::{Summary, TimingData};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct DayTimings {
+    day: u32,
+    parsing_ns: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part1_ns: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part2_ns: Option<u64>,
+}
+
+impl DayTimings {
+    fn new(day: u32, timing: &TimingData) -> Self {
+        let nanos = |d: Duration| d.as_nanos() as u64;
+        DayTimings {
+            day,
+            parsing_ns: nanos(timing.parsing),
+            part1_ns: timing.part1.map(nanos),
+            part2_ns: timing.part2.map(nanos),
+        }
+    }
+
+    /// Keeps the previous timings of the parts that did not run this time
+    fn merge(self, previous: &DayTimings) -> Self {
+        DayTimings {
+            part1_ns: self.part1_ns.or(previous.part1_ns),
+            part2_ns: self.part2_ns.or(previous.part2_ns),
+            ..self
+        }
+    }
+
+    fn phases(&self) -> [(&'static str, Option<Duration>); 3] {
+        [
+            ("parsing", Some(Duration::from_nanos(self.parsing_ns))),
+            ("part 1", self.part1_ns.map(Duration::from_nanos)),
+            ("part 2", self.part2_ns.map(Duration::from_nanos)),
+        ]
+    }
+}
+
+/// When a phase counts as slower than its baseline
+#[derive(Debug, Clone, Copy)]
+pub struct Thresholds {
+    /// Flag phases that take more than `ratio` times their baseline duration
+    pub ratio: f64,
+    /// Phases faster than this in the baseline are too noisy to be flagged
+    pub floor: Duration,
+}
+
+#[derive(Debug)]
+pub struct Slowdown {
+    day: u32,
+    phase: &'static str,
+    baseline: Duration,
+    current: Duration,
+}
+
+impl fmt::Display for Slowdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "Day {}, {}: {:?} -> {:?} (x{:.2})",
+            self.day,
+            self.phase,
+            self.baseline,
+            self.current,
+            self.current.as_secs_f64() / self.baseline.as_secs_f64()
+        )
+    }
+}
+
+/// Reference timings of a year, stored next to the expected answers
+#[derive(Debug, Default)]
+pub struct Baseline {
+    days: Vec<DayTimings>,
+}
+
+impl Baseline {
+    fn path(year: u32) -> String {
+        format!("./results/{year}.timings.json")
+    }
+
+    pub fn load(year: u32) -> Result<Self, Box<dyn std::error::Error>> {
+        let f = File::open(Self::path(year))?;
+        let days: Vec<DayTimings> = serde_json::from_reader(f)?;
+
+        Ok(Self { days })
+    }
+
+    /// Same as `load`, but a missing baseline file is an empty baseline
+    pub fn load_or_default(year: u32) -> Result<Self, Box<dyn std::error::Error>> {
+        match Self::load(year) {
+            Err(err)
+                if err
+                    .downcast_ref::<std::io::Error>()
+                    .is_some_and(|err| err.kind() == std::io::ErrorKind::NotFound) =>
+            {
+                Ok(Self::default())
+            }
+            res => res,
+        }
+    }
+
+    pub fn save(&self, year: u32) -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = BufWriter::new(File::create(Self::path(year))?);
+        let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+        let mut ser = serde_json::Serializer::with_formatter(&mut writer, formatter);
+        self.days.serialize(&mut ser)?;
+        writeln!(writer)?;
+        Ok(())
+    }
+
+    /// Replaces the baseline of every day that ran in `summary`, keeping the others. Phases
+    /// that did not complete keep their previous timings.
+    pub fn update(&mut self, summary: &Summary) {
+        self.update_days(
+            summary
+                .completed_timings()
+                .map(|(day, timing)| DayTimings::new(day, timing)),
+        );
+    }
+
+    fn update_days(&mut self, days: impl IntoIterator<Item = DayTimings>) {
+        for timings in days {
+            match self.days.binary_search_by_key(&timings.day, |d| d.day) {
+                Ok(index) => self.days[index] = timings.merge(&self.days[index]),
+                Err(index) => self.days.insert(index, timings),
+            }
+        }
+    }
+
+    pub fn compare(&self, summary: &Summary, thresholds: &Thresholds) -> Vec<Slowdown> {
+        self.compare_days(
+            summary
+                .completed_timings()
+                .map(|(day, timing)| DayTimings::new(day, timing)),
+            thresholds,
+        )
+    }
+
+    fn compare_days(
+        &self,
+        days: impl IntoIterator<Item = DayTimings>,
+        thresholds: &Thresholds,
+    ) -> Vec<Slowdown> {
+        let mut slowdowns = Vec::new();
+        for current in days {
+            let day = current.day;
+            let Some(baseline) = self.days.iter().find(|d| d.day == day) else {
+                continue;
+            };
+
+            for ((phase, baseline), (_, current)) in
+                baseline.phases().into_iter().zip(current.phases())
+            {
+                let (Some(baseline), Some(current)) = (baseline, current) else {
+                    continue;
+                };
+                if baseline >= thresholds.floor
+                    && current.as_secs_f64() > baseline.as_secs_f64() * thresholds.ratio
+                {
+                    slowdowns.push(Slowdown {
+                        day,
+                        phase,
+                        baseline,
+                        current,
+                    });
+                }
+            }
+        }
+        slowdowns
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MS: u64 = 1_000_000;
+
+    fn timings(day: u32, parsing: u64, part1: Option<u64>, part2: Option<u64>) -> DayTimings {
+        DayTimings {
+            day,
+            parsing_ns: parsing * MS,
+            part1_ns: part1.map(|ms| ms * MS),
+            part2_ns: part2.map(|ms| ms * MS),
+        }
+    }
+
+    fn phases(baseline: &Baseline) -> Vec<(u32, u64, Option<u64>, Option<u64>)> {
+        baseline
+            .days
+            .iter()
+            .map(|d| {
+                let ms = |ns: u64| ns / MS;
+                (
+                    d.day,
+                    ms(d.parsing_ns),
+                    d.part1_ns.map(ms),
+                    d.part2_ns.map(ms),
+                )
+            })
+            .collect()
+    }
+
+    fn slowdowns(slowdowns: Vec<Slowdown>) -> Vec<(u32, &'static str)> {
+        slowdowns.iter().map(|s| (s.day, s.phase)).collect()
+    }
+
+    const THRESHOLDS: Thresholds = Thresholds {
+        ratio: 1.5,
+        floor: Duration::from_millis(10),
+    };
+
+    #[test]
+    fn merge_keeps_previous_part_timings() {
+        let merged = timings(1, 5, None, Some(30)).merge(&timings(1, 4, Some(20), Some(25)));
+        assert_eq!(
+            (merged.parsing_ns, merged.part1_ns, merged.part2_ns),
+            (5 * MS, Some(20 * MS), Some(30 * MS))
+        );
+    }
+
+    #[test]
+    fn update_keeps_days_in_order() {
+        let mut baseline = Baseline::default();
+        baseline.update_days([
+            timings(7, 1, Some(2), None),
+            timings(2, 3, Some(4), Some(5)),
+        ]);
+        baseline.update_days([timings(7, 6, None, Some(8)), timings(4, 9, None, None)]);
+        assert_eq!(
+            phases(&baseline),
+            [
+                (2, 3, Some(4), Some(5)),
+                (4, 9, None, None),
+                (7, 6, Some(2), Some(8)),
+            ]
+        );
+    }
+
+    #[test]
+    fn compare_flags_phases_above_the_ratio() {
+        let baseline = Baseline {
+            days: vec![timings(1, 100, Some(100), Some(100))],
+        };
+        let current = timings(1, 150, Some(151), Some(300));
+        assert_eq!(
+            slowdowns(baseline.compare_days([current], &THRESHOLDS)),
+            [(1, "part 1"), (1, "part 2")]
+        );
+    }
+
+    #[test]
+    fn compare_ignores_phases_below_the_floor() {
+        let baseline = Baseline {
+            days: vec![timings(1, 9, Some(10), None)],
+        };
+        let current = timings(1, 90, Some(100), Some(100));
+        assert_eq!(
+            slowdowns(baseline.compare_days([current], &THRESHOLDS)),
+            [(1, "part 1")]
+        );
+    }
+
+    #[test]
+    fn compare_ignores_days_and_parts_without_both_timings() {
+        let baseline = Baseline {
+            days: vec![timings(1, 100, None, Some(100))],
+        };
+        let current = [
+            timings(1, 100, Some(500), None),
+            timings(2, 500, None, None),
+        ];
+        assert!(baseline.compare_days(current, &THRESHOLDS).is_empty());
+    }
+}
@@ -0,0 +1,25 @@
+//! Shape of the events, shared with build.rs
+
+/// Most days an event has had, until 2024
+pub const MAX_DAYS: u32 = 25;
+
+/// Number of days of the event of `year`, down from 25 to 12 since 2025
+pub fn days_in(year: u32) -> u32 {
+    if year >= 2025 {
+        12
+    } else {
+        MAX_DAYS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn twelve_days_from_2025() {
+        assert_eq!(days_in(2015), 25);
+        assert_eq!(days_in(2024), 25);
+        assert_eq!(days_in(2025), 12);
+    }
+}
@@ -0,0 +1,253 @@
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc::inputs;
+
+use crate::selection::DaySelection;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const SESSION_VAR: &str = "AOC_SESSION";
+const USER_AGENT: &str = concat!(
+    "aoc/",
+    env!("CARGO_PKG_VERSION"),
+    " (paulcacheux@gmail.com)"
+);
+
+/// Session cookie of the Advent of Code account, from the environment or the `.env` file
+pub fn session() -> Result<String, Box<dyn std::error::Error>> {
+    if let Ok(session) = std::env::var(SESSION_VAR) {
+        return Ok(session);
+    }
+
+    let dotenv = match std::fs::read_to_string(".env") {
+        Ok(dotenv) => dotenv,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(format!("cannot read .env: {err}").into()),
+    };
+    dotenv_session(&dotenv)
+        .ok_or_else(|| format!("{SESSION_VAR} is not set, in the environment or in .env").into())
+}
+
+/// Last value of the session variable in the content of a `.env` file
+fn dotenv_session(dotenv: &str) -> Option<String> {
+    dotenv
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.trim().split_once('=')?;
+            (key.trim().trim_start_matches("export ").trim() == SESSION_VAR).then_some(value)
+        })
+        .map(|value| value.trim().trim_matches(['"', '\'']).to_owned())
+        .last()
+}
+
+/// HTTP client for the site, identified as asked by its maintainers
+pub fn agent() -> ureq::Agent {
+    ureq::AgentBuilder::new()
+        .user_agent(USER_AGENT)
+        .timeout(Duration::from_secs(30))
+        .build()
+}
+
+/// Days since the Unix epoch of a date of the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// When the input of a day is released: midnight EST, 05:00 UTC
+fn release_time(year: u32, day: u32) -> SystemTime {
+    let days = days_from_civil(year.into(), 12, day.into());
+    UNIX_EPOCH + Duration::from_secs((days * 86400 + 5 * 3600) as u64)
+}
+
+/// Writes `content` to `path` through a temporary file, so that an interrupted download
+/// never leaves a partial input behind
+fn write_atomic(path: &Path, content: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|err| format!("cannot create {}: {err}", dir.display()))?;
+    }
+    let tmp = path.with_extension("txt.tmp");
+    std::fs::write(&tmp, content)
+        .map_err(|err| format!("cannot write {}: {err}", tmp.display()))?;
+    std::fs::rename(&tmp, path).map_err(|err| {
+        let _ = std::fs::remove_file(&tmp);
+        format!("cannot write {}: {err}", path.display()).into()
+    })
+}
+
+fn fetch_input(
+    agent: &ureq::Agent,
+    url: &str,
+    session: &str,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let response = match agent
+        .get(url)
+        .set("Cookie", &format!("session={session}"))
+        .call()
+    {
+        Ok(response) => response,
+        Err(ureq::Error::Status(status, response)) => {
+            return Err(format!("{url} returned {status} {}", response.status_text()).into());
+        }
+        Err(err) => return Err(format!("cannot fetch {url}: {err}").into()),
+    };
+    if response.status() != 200 {
+        return Err(format!(
+            "{url} returned {} {}",
+            response.status(),
+            response.status_text()
+        )
+        .into());
+    }
+    let input = response
+        .into_string()
+        .map_err(|err| format!("cannot read the response of {url}: {err}"))?;
+    if input.is_empty() {
+        return Err(format!("{url} returned an empty input").into());
+    }
+    Ok(input)
+}
+
+/// Downloads the released inputs of the selected days that are not in `inputs/` yet.
+/// Inputs already there are never fetched again.
+pub fn fetch(
+    years: &[u32],
+    days: &DaySelection,
+    base_url: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let now = SystemTime::now();
+    let missing: Vec<(u32, u32)> = years
+        .iter()
+        .flat_map(|&year| days.days().map(move |day| (year, day)))
+        .filter(|&(year, day)| day <= aoc::days_in(year))
+        .filter(|&(year, day)| release_time(year, day) <= now)
+        .filter(|&(year, day)| !Path::new(&inputs::real_path(year, day)).exists())
+        .collect();
+    if missing.is_empty() {
+        println!("Every released input is already there");
+        return Ok(());
+    }
+
+    let session = session()?;
+    let agent = agent();
+    let base_url = base_url.trim_end_matches('/');
+
+    let mut failed = 0;
+    for (year, day) in missing {
+        let url = format!("{base_url}/{year}/day/{day}/input");
+        let path = inputs::real_path(year, day);
+        match fetch_input(&agent, &url, &session)
+            .and_then(|input| write_atomic(Path::new(&path), &input))
+        {
+            Ok(()) => println!("Fetched {path}"),
+            Err(err) => {
+                eprintln!("Error fetching {year} day {day}: {err}");
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{failed} inputs could not be fetched").into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    use super::*;
+
+    #[test]
+    fn civil_days() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        assert_eq!(days_from_civil(2024, 12, 1), 20058);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+    }
+
+    #[test]
+    fn release_at_midnight_est() {
+        // 2024-12-01T05:00:00Z
+        assert_eq!(
+            release_time(2024, 1),
+            UNIX_EPOCH + Duration::from_secs(1733029200)
+        );
+        assert_eq!(
+            release_time(2024, 25)
+                .duration_since(release_time(2024, 1))
+                .unwrap(),
+            Duration::from_secs(24 * 86400)
+        );
+    }
+
+    #[test]
+    fn session_in_dotenv() {
+        assert_eq!(dotenv_session("AOC_SESSION=abc"), Some("abc".to_owned()));
+        assert_eq!(
+            dotenv_session("OTHER=1\n  export AOC_SESSION = \"abc\"\n"),
+            Some("abc".to_owned())
+        );
+        assert_eq!(
+            dotenv_session("AOC_SESSION='old'\nAOC_SESSION='new'"),
+            Some("new".to_owned())
+        );
+        assert_eq!(dotenv_session("AOC_SESSION_OLD=abc\n# AOC_SESSION\n"), None);
+    }
+
+    /// Serves a single request with `response`, returning the URL to request and the
+    /// request line and headers that were received
+    fn serve(response: &'static str) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/2024/day/1/input", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request = BufReader::new(&stream)
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|line| !line.is_empty())
+                .collect();
+            stream.write_all(response.as_bytes()).unwrap();
+            request
+        });
+        (url, server)
+    }
+
+    #[test]
+    fn fetch_inputs() {
+        let (url, server) =
+            serve("HTTP/1.1 200 OK\r\nContent-Length: 6\r\nConnection: close\r\n\r\n1 2\n3\n");
+        assert_eq!(fetch_input(&agent(), &url, "abc").unwrap(), "1 2\n3\n");
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2024/day/1/input HTTP/1.1");
+        assert!(
+            request.iter().any(|h| h == "Cookie: session=abc"),
+            "{request:?}"
+        );
+        assert!(
+            request.iter().any(|h| h.starts_with("User-Agent: aoc/")),
+            "{request:?}"
+        );
+
+        let (url, server) =
+            serve("HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+        let err = fetch_input(&agent(), &url, "abc").unwrap_err();
+        assert_eq!(err.to_string(), format!("{url} returned 400 Bad Request"));
+        server.join().unwrap();
+
+        let (url, server) =
+            serve("HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+        let err = fetch_input(&agent(), &url, "abc").unwrap_err();
+        assert_eq!(err.to_string(), format!("{url} returned an empty input"));
+        server.join().unwrap();
+    }
+}
@@ -1,3 +1,5 @@
+use crate::parse::{self, ParseError};
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
     pub data: Vec<T>,
@@ -16,28 +18,45 @@ impl<T: Clone> Grid<T> {
 }
 
 impl<T> Grid<T> {
-    pub fn parse<F: Fn(char) -> T>(input: &str, mapper: F) -> Self {
-        let mut heights = Vec::new();
+    /// Parses a grid with one row per line, `mapper` returning `None` for characters that
+    /// are not cells
+    pub fn parse<F: Fn(char) -> Option<T>>(input: &str, mapper: F) -> Result<Self, ParseError> {
+        let mut data = Vec::new();
         let mut width = None;
         let mut height = 0;
 
-        for line in input.lines() {
-            let line = line.trim();
-            if let Some(w) = width {
-                assert_eq!(w, line.len());
-            } else {
-                width = Some(line.len());
+        for line in parse::lines(input) {
+            let row = line.text.trim();
+            let mut row_width = 0;
+            for (offset, c) in row.char_indices() {
+                let cell = mapper(c).ok_or_else(|| {
+                    line.error(
+                        &row[offset..offset + c.len_utf8()],
+                        format!("unexpected character {c:?}"),
+                    )
+                })?;
+                data.push(cell);
+                row_width += 1;
+            }
+
+            match width {
+                Some(width) if width != row_width => {
+                    return Err(line.error(
+                        row,
+                        format!("expected a row of {width} cells, found {row_width}"),
+                    ));
+                }
+                Some(_) => {}
+                None => width = Some(row_width),
             }
-            // heights.extend(line.chars().map(|c| c.to_string().parse::<u8>().unwrap()));
-            heights.extend(line.chars().map(&mapper));
             height += 1;
         }
 
-        Grid {
-            data: heights,
+        Ok(Grid {
+            data,
             width: width.unwrap_or_default(),
             height,
-        }
+        })
     }
 
     pub fn get(&self, x: usize, y: usize) -> &T {
@@ -100,3 +119,43 @@ pub enum Direction {
     West,
     East,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wall(c: char) -> Option<bool> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn parse_rows() {
+        let grid = Grid::parse("#..\n.#.\n", wall).unwrap();
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid.data, [true, false, false, false, true, false]);
+    }
+
+    #[test]
+    fn unexpected_character() {
+        let err = Grid::parse("#..\n.é#\n", wall).unwrap_err();
+        assert_eq!((err.line, err.column, err.length), (2, 2, 1));
+        assert_eq!(err.message, "unexpected character 'é'");
+    }
+
+    #[test]
+    fn ragged_row() {
+        let err = Grid::parse("#..\n.#\n...\n", wall).unwrap_err();
+        assert_eq!((err.line, err.column, err.length), (2, 1, 2));
+        assert_eq!(err.message, "expected a row of 3 cells, found 2");
+    }
+
+    #[test]
+    fn indented_rows_locate_errors_in_the_line() {
+        let err = Grid::parse("  #x.\n", wall).unwrap_err();
+        assert_eq!((err.column, err.length), (4, 1));
+    }
+}
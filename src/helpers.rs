@@ -1,107 +1,890 @@
+use std::any::TypeId;
 use std::fmt;
 use std::fs::File;
-use std::time::Duration;
+use std::hint::black_box;
+use std::io::{BufWriter, Write};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use crate::traits::{ParseInput, Part1, Part2, Solution};
-use serde::Deserialize;
+use crate::allocs::{self, AllocStats};
+use crate::inputs::DayInput;
+use crate::panics::{self, PanicReport};
+use crate::parse::ParseError;
+use crate::traits::{NoAnswer, ParseInput, Part1, Part2, Solution};
+use crate::watchdog;
+use serde::{Deserialize, Serialize};
 
 pub struct Results {
     days: Vec<DayExpectedResult>,
 }
 
 impl Results {
+    fn path(year: u32) -> String {
+        format!("./results/{year}.json")
+    }
+
     pub fn parse(year: u32) -> Result<Self, Box<dyn std::error::Error>> {
-        let f = File::open(format!("./results/{year}.json"))?;
+        Self::from_path(&Self::path(year))
+    }
+
+    /// Same as `parse`, but a missing results file means no answers are known yet
+    pub fn parse_or_default(year: u32) -> Result<Self, Box<dyn std::error::Error>> {
+        match Self::parse(year) {
+            Err(err)
+                if err
+                    .downcast_ref::<std::io::Error>()
+                    .is_some_and(|err| err.kind() == std::io::ErrorKind::NotFound) =>
+            {
+                Ok(Self { days: Vec::new() })
+            }
+            res => res,
+        }
+    }
+
+    pub fn save(&self, year: u32) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Self::path(year);
+        // some years are indented with tabs, keep them that way
+        let indent: &[u8] = match std::fs::read_to_string(&path) {
+            Ok(content) if content.starts_with("[\n\t") => b"\t",
+            _ => b"    ",
+        };
+
+        let mut writer = BufWriter::new(File::create(&path)?);
+        let formatter = serde_json::ser::PrettyFormatter::with_indent(indent);
+        let mut ser = serde_json::Serializer::with_formatter(&mut writer, formatter);
+        self.days.serialize(&mut ser)?;
+        writeln!(writer)?;
+        Ok(())
+    }
+
+    /// Sets the expected answer of a part, keeping days in order. An answer that differs
+    /// from the known one is only replaced with `force`.
+    pub fn record(&mut self, day: usize, part: u32, answer: &str, force: bool) -> Recorded {
+        let index = match self.days.iter().position(|r| r.day == day) {
+            Some(index) => index,
+            None => {
+                let index = self
+                    .days
+                    .iter()
+                    .position(|r| r.day > day)
+                    .unwrap_or(self.days.len());
+                self.days.insert(
+                    index,
+                    DayExpectedResult {
+                        day,
+                        part1: None,
+                        part2: None,
+                    },
+                );
+                index
+            }
+        };
+
+        let expected = match part {
+            1 => &mut self.days[index].part1,
+            2 => &mut self.days[index].part2,
+            _ => unreachable!(),
+        };
+        match expected {
+            None => {
+                *expected = Some(answer.to_owned());
+                Recorded::Inserted
+            }
+            Some(expected) if expected == answer => Recorded::Unchanged,
+            Some(expected) if force => Recorded::Overwritten {
+                previous: std::mem::replace(expected, answer.to_owned()),
+            },
+            Some(expected) => Recorded::Conflict {
+                expected: expected.clone(),
+            },
+        }
+    }
+
+    pub fn from_path(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let f = File::open(path)?;
         let days: Vec<DayExpectedResult> = serde_json::from_reader(f)?;
 
         Ok(Self { days })
     }
 
+    /// Expected answers of a single day, e.g. those of an example
+    pub fn single(day: usize, part1: Option<String>, part2: Option<String>) -> Self {
+        Self {
+            days: vec![DayExpectedResult { day, part1, part2 }],
+        }
+    }
+
     pub fn results_for_day(&self, day: usize) -> Option<&DayExpectedResult> {
         self.days.iter().find(|&r| r.day == day)
     }
 }
 
-#[derive(Debug, Deserialize)]
+/// Expected answers of a day. A part without an expected answer is reported as unchecked.
+#[derive(Debug, Serialize, Deserialize)]
 pub struct DayExpectedResult {
     day: usize,
-    part1: String,
-    part2: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+/// What `Results::record` did with an answer
+#[derive(Debug, PartialEq, Eq)]
+pub enum Recorded {
+    Inserted,
+    Unchanged,
+    Overwritten {
+        previous: String,
+    },
+    /// A different answer is already known, and was kept
+    Conflict {
+        expected: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong {
+        expected: String,
+    },
+    MissingExpected,
+    NoSolution,
+    Panicked(PanicReport),
+    TimedOut,
+    Skipped,
+    /// The part has no answer, see `NoAnswer`
+    NotApplicable,
+    /// Parsing rejected the input
+    InvalidInput(ParseError),
+    /// The input or its expected answers could not be loaded
+    LoadError(String),
+}
+
+impl Verdict {
+    pub fn is_regression(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Wrong { .. }
+                | Verdict::Panicked(_)
+                | Verdict::TimedOut
+                | Verdict::InvalidInput(_)
+                | Verdict::LoadError(_)
+        )
+    }
+
+    /// Short machine-friendly name of the verdict, used by the JSON and CSV reports
+    pub fn status(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong { .. } => "wrong",
+            Verdict::MissingExpected => "missing_expected",
+            Verdict::NoSolution => "no_solution",
+            Verdict::Panicked(_) => "panicked",
+            Verdict::TimedOut => "timed_out",
+            Verdict::Skipped => "skipped",
+            Verdict::NotApplicable => "not_applicable",
+            Verdict::InvalidInput(_) => "invalid_input",
+            Verdict::LoadError(_) => "load_error",
+        }
+    }
+
+    /// Details of a failure that has no output to show, e.g. a panic message
+    pub fn error(&self) -> Option<String> {
+        match self {
+            Verdict::Panicked(report) => Some(report.to_string()),
+            Verdict::InvalidInput(err) => Some(err.to_string()),
+            Verdict::LoadError(err) => Some(err.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Verdict::Correct => write!(f, "ok"),
+            Verdict::Wrong { expected } => write!(f, "WRONG (expected {expected})"),
+            Verdict::MissingExpected => write!(f, "no expected value"),
+            Verdict::NoSolution => write!(f, "no solution"),
+            Verdict::Panicked(PanicReport {
+                location: Some(location),
+                ..
+            }) => write!(f, "PANICKED at {location}"),
+            Verdict::Panicked(_) => write!(f, "PANICKED"),
+            Verdict::TimedOut => write!(f, "TIMED OUT"),
+            Verdict::Skipped => write!(f, "skipped"),
+            Verdict::NotApplicable => write!(f, "n/a"),
+            Verdict::InvalidInput(_) => write!(f, "INVALID INPUT"),
+            Verdict::LoadError(_) => write!(f, "LOAD ERROR"),
+        }
+    }
+}
+
+impl DayExpectedResult {
+    pub fn has_answer(&self, part: u32) -> bool {
+        match part {
+            1 => self.part1.is_some(),
+            2 => self.part2.is_some(),
+            _ => unreachable!(),
+        }
+    }
+
+    fn check(&self, part: u32, output: &str) -> Verdict {
+        let expected_output = match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => unreachable!(),
+        };
+        let Some(expected_output) = expected_output else {
+            return Verdict::MissingExpected;
+        };
+        if expected_output == output {
+            Verdict::Correct
+        } else {
+            Verdict::Wrong {
+                expected: expected_output.to_owned(),
+            }
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct DayResult {
-    day: u32,
-    part: u32,
-    output: String,
-    elapsed: Duration,
+    pub day: u32,
+    pub part: u32,
+    pub output: String,
+    pub elapsed: Duration,
+    pub verdict: Verdict,
+    pub stats: Option<Stats>,
+    /// Allocations of the part, with the `alloc-stats` feature
+    pub allocs: Option<AllocStats>,
 }
 
 impl DayResult {
-    fn check_expected(&self, expected: Option<&DayExpectedResult>) {
-        if let Some(expected) = expected {
-            let expected_output = match self.part {
-                1 => expected.part1.as_str(),
-                2 => expected.part2.as_str(),
-                _ => unreachable!(),
-            };
-            assert_eq!(expected_output, self.output);
+    /// Result of a part that did not produce any output
+    fn failed(day: u32, part: u32, elapsed: Duration, verdict: Verdict) -> Self {
+        DayResult {
+            day,
+            part,
+            output: String::new(),
+            elapsed,
+            verdict,
+            stats: None,
+            allocs: None,
         }
     }
+
+    fn not_applicable(day: u32, part: u32) -> Self {
+        Self::failed(day, part, Duration::ZERO, Verdict::NotApplicable)
+    }
+
+    /// Whether the part ran to completion, be its output right or wrong
+    fn completed(&self) -> bool {
+        !matches!(
+            self.verdict,
+            Verdict::TimedOut
+                | Verdict::Panicked(_)
+                | Verdict::NotApplicable
+                | Verdict::InvalidInput(_)
+        )
+    }
+
+    /// Time spent in the part, `None` if it has no answer to compute
+    pub fn timing(&self) -> Option<Duration> {
+        (self.verdict != Verdict::NotApplicable).then_some(self.elapsed)
+    }
+
+    fn set_stats(&mut self, stats: Stats) {
+        self.elapsed = stats.median;
+        self.stats = Some(stats);
+    }
 }
 
 impl fmt::Display for DayResult {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match &self.verdict {
+            Verdict::TimedOut => {
+                return write!(
+                    f,
+                    "Day {}, Part {}, timed out after {:?}",
+                    self.day, self.part, self.elapsed
+                );
+            }
+            Verdict::Panicked(report) => {
+                return write!(
+                    f,
+                    "Day {}, Part {}, panicked after {:?}: {report}",
+                    self.day, self.part, self.elapsed
+                );
+            }
+            Verdict::NotApplicable => {
+                return write!(f, "Day {}, Part {}: n/a", self.day, self.part);
+            }
+            _ => {}
+        }
+
         write!(
             f,
             "Day {}, Part {}, in {:?}\n{}",
             self.day, self.part, self.elapsed, self.output,
-        )
+        )?;
+        if self.verdict.is_regression() {
+            write!(f, "\n{}", self.verdict)?;
+        }
+        if let Some(stats) = &self.stats {
+            write!(f, "\nPart {}: {}", self.part, stats)?;
+        }
+        if let Some(allocs) = &self.allocs {
+            write!(f, "\nPart {}: {}", self.part, allocs)?;
+        }
+        Ok(())
     }
 }
 
 macro_rules! inner_run {
-    ($P:tt, $F:expr, $input:expr) => {{
-        let start = std::time::Instant::now();
-        let output = $F(&$input);
-        let elapsed = start.elapsed();
+    ($P:tt, $F:expr, $input:expr, $expected:expr, $timeout:expr) => {{
+        let input = Arc::clone(&$input);
+        let run = watchdog::with_timeout($timeout, move || {
+            let start = Instant::now();
+            let output = panics::catch(|| allocs::measure(|| $F(&input)));
+            let elapsed = start.elapsed();
+            let output = output.map(|(output, allocs)| (output.to_string(), allocs));
+            (output, elapsed)
+        });
 
-        DayResult {
-            day: D,
-            part: $P,
-            output: output.to_string(),
-            elapsed,
+        match run {
+            Some((Ok((output, allocs)), elapsed)) => {
+                let verdict = $expected.map_or(Verdict::MissingExpected, |e| e.check($P, &output));
+                DayResult {
+                    day: D,
+                    part: $P,
+                    output,
+                    elapsed,
+                    verdict,
+                    stats: None,
+                    allocs,
+                }
+            }
+            Some((Err(panic), elapsed)) => {
+                DayResult::failed(D, $P, elapsed, Verdict::Panicked(panic))
+            }
+            None => DayResult::failed(D, $P, $timeout.unwrap_or_default(), Verdict::TimedOut),
         }
     }};
 }
 
+/// Runner settings shared by every day of an invocation
+#[derive(Debug, Clone, Default)]
+pub struct RunConfig {
+    pub bench: Option<BenchConfig>,
+    /// Only run this part, both if `None`
+    pub part: Option<u32>,
+    /// Give up on a phase (parsing or part) that runs for longer than this
+    pub timeout: Option<Duration>,
+}
+
+impl RunConfig {
+    fn runs_part(&self, part: u32) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    /// Untimed runs done before sampling
+    pub warmup: usize,
+    /// Number of timed runs
+    pub iterations: usize,
+}
+
+impl BenchConfig {
+    fn sample<T>(&self, mut f: impl FnMut() -> T) -> Stats {
+        for _ in 0..self.warmup {
+            black_box(f());
+        }
+
+        let samples = (0..self.iterations)
+            .map(|_| {
+                let start = Instant::now();
+                let output = black_box(f());
+                let elapsed = start.elapsed();
+                drop(output);
+                elapsed
+            })
+            .collect();
+        Stats::from_samples(samples)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is required");
+        samples.sort();
+
+        let count = samples.len();
+        let median = if count % 2 == 0 {
+            (samples[count / 2 - 1] + samples[count / 2]) / 2
+        } else {
+            samples[count / 2]
+        };
+        let mean = samples.iter().sum::<Duration>() / count as u32;
+        let variance = samples
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        Stats {
+            samples: count,
+            min: samples[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "min {:?}, median {:?}, mean {:?}, stddev {:?} ({} runs)",
+            self.min, self.median, self.mean, self.stddev, self.samples
+        )
+    }
+}
+
+/// Durations of each phase. When benchmarking, these are the medians.
+#[derive(Debug, Clone, Copy)]
 pub struct TimingData {
-    #[allow(dead_code)]
     pub parsing: Duration,
-    pub part1: Duration,
-    pub part2: Duration,
-}
-
-pub fn run<A: Solution<D>, const D: u32>(
-    input: &str,
-    expected: Option<&DayExpectedResult>,
-) -> TimingData {
-    let start = std::time::Instant::now();
-    let input = <A as ParseInput<D>>::parse_input(input);
-    let parsing_elapsed = start.elapsed();
-    println!("Day {D}, parsing in {parsing_elapsed:?}");
-
-    let part1 = inner_run!(Part1, A::part1, input);
-    println!("{part1}");
-    part1.check_expected(expected);
-
-    let part2 = inner_run!(Part2, A::part2, input);
-    println!("{part2}");
-    part2.check_expected(expected);
-
-    TimingData {
-        parsing: parsing_elapsed,
-        part1: part1.elapsed,
-        part2: part2.elapsed,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+impl TimingData {
+    /// Time spent in the parts that ran
+    pub fn parts(&self) -> Duration {
+        self.part1.unwrap_or_default() + self.part2.unwrap_or_default()
+    }
+}
+
+pub struct DayReport {
+    pub day: u32,
+    /// Name of the input when it is not the real one, e.g. an example
+    pub input: Option<String>,
+    pub timing: TimingData,
+    pub parsing_stats: Option<Stats>,
+    /// Allocations of parsing, with the `alloc-stats` feature
+    pub parsing_allocs: Option<AllocStats>,
+    /// Why parsing did not complete, if it did not
+    pub parsing_failure: Option<Verdict>,
+    pub part1: Option<DayResult>,
+    pub part2: Option<DayResult>,
+}
+
+impl DayReport {
+    /// Report of a day whose parsing failed: the parts never ran and share its verdict
+    fn parsing_failed<const D: u32>(
+        config: &RunConfig,
+        elapsed: Duration,
+        verdict: Verdict,
+    ) -> Self {
+        let part1 = config
+            .runs_part(Part1)
+            .then(|| DayResult::failed(D, Part1, Duration::ZERO, verdict.clone()));
+        let part2 = config
+            .runs_part(Part2)
+            .then(|| DayResult::failed(D, Part2, Duration::ZERO, verdict.clone()));
+
+        DayReport {
+            day: D,
+            input: None,
+            timing: TimingData {
+                parsing: elapsed,
+                part1: part1.as_ref().and_then(DayResult::timing),
+                part2: part2.as_ref().and_then(DayResult::timing),
+            },
+            parsing_stats: None,
+            parsing_allocs: None,
+            parsing_failure: Some(verdict),
+            part1,
+            part2,
+        }
+    }
+
+    pub fn verdicts(&self) -> (Verdict, Verdict) {
+        let verdict = |part: &Option<DayResult>| {
+            part.as_ref()
+                .map_or(Verdict::Skipped, |part| part.verdict.clone())
+        };
+        (verdict(&self.part1), verdict(&self.part2))
+    }
+}
+
+impl fmt::Display for DayReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        let day = match &self.input {
+            Some(input) => format!("Day {} ({input})", self.day),
+            None => format!("Day {}", self.day),
+        };
+        match &self.parsing_failure {
+            Some(Verdict::TimedOut) => {
+                return write!(
+                    f,
+                    "{day}, parsing timed out after {:?}",
+                    self.timing.parsing
+                );
+            }
+            Some(Verdict::Panicked(report)) => {
+                return write!(
+                    f,
+                    "{day}, parsing panicked after {:?}: {report}",
+                    self.timing.parsing
+                );
+            }
+            Some(Verdict::InvalidInput(err)) => {
+                return write!(f, "{day}, invalid input at {err}");
+            }
+            _ => {}
+        }
+
+        if let Some(stats) = &self.parsing_stats {
+            write!(f, "{day}, parsing: {stats}")?;
+        } else {
+            write!(f, "{day}, parsing in {:?}", self.timing.parsing)?;
+        }
+        if let Some(allocs) = &self.parsing_allocs {
+            write!(f, "\nParsing: {allocs}")?;
+        }
+        for part in [&self.part1, &self.part2].into_iter().flatten() {
+            write!(f, "\n{part}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether `T`, the output of a part, is an answer and not `NoAnswer`
+pub(crate) fn has_answer<T: 'static>() -> bool {
+    TypeId::of::<T>() != TypeId::of::<NoAnswer>()
+}
+
+pub fn run<A, const D: u32>(
+    input: &DayInput,
+    config: &RunConfig,
+) -> Result<DayReport, Box<dyn std::error::Error>>
+where
+    A: Solution<D> + 'static,
+    <A as ParseInput<D>>::Parsed: Send + Sync + 'static,
+    <A as Solution<D>>::Part1Output: 'static,
+    <A as Solution<D>>::Part2Output: 'static,
+{
+    let expected = input
+        .results
+        .as_ref()
+        .and_then(|r| r.results_for_day(D as usize));
+    let params: Arc<<A as ParseInput<D>>::Params> = Arc::new(match &input.params {
+        Some(params) => serde_json::from_value(params.clone())
+            .map_err(|err| format!("invalid params for day {D}: {err}"))?,
+        None => Default::default(),
+    });
+
+    let raw_input: Arc<str> = Arc::from(input.input.as_str());
+    let parsed = {
+        let (raw_input, params) = (Arc::clone(&raw_input), Arc::clone(&params));
+        watchdog::with_timeout(config.timeout, move || {
+            let start = Instant::now();
+            let input = panics::catch(|| {
+                allocs::measure(|| <A as ParseInput<D>>::try_parse_input(&raw_input, &params))
+            });
+            (input, start.elapsed())
+        })
+    };
+    let (input, parsing_allocs, mut parsing_elapsed) = match parsed {
+        Some((Ok((Ok(input), allocs)), elapsed)) => (input, allocs, elapsed),
+        Some((Ok((Err(err), _)), elapsed)) => {
+            return Ok(DayReport::parsing_failed::<D>(
+                config,
+                elapsed,
+                Verdict::InvalidInput(err),
+            ));
+        }
+        Some((Err(panic), elapsed)) => {
+            return Ok(DayReport::parsing_failed::<D>(
+                config,
+                elapsed,
+                Verdict::Panicked(panic),
+            ));
+        }
+        None => {
+            let timeout = config.timeout.unwrap_or_default();
+            return Ok(DayReport::parsing_failed::<D>(
+                config,
+                timeout,
+                Verdict::TimedOut,
+            ));
+        }
+    };
+    let input = Arc::new(input);
+
+    let mut part1 = config.runs_part(Part1).then(|| {
+        if has_answer::<<A as Solution<D>>::Part1Output>() {
+            inner_run!(Part1, A::part1, input, expected, config.timeout)
+        } else {
+            DayResult::not_applicable(D, Part1)
+        }
+    });
+    let mut part2 = config.runs_part(Part2).then(|| {
+        if has_answer::<<A as Solution<D>>::Part2Output>() {
+            inner_run!(Part2, A::part2, input, expected, config.timeout)
+        } else {
+            DayResult::not_applicable(D, Part2)
+        }
+    });
+
+    let mut parsing_stats = None;
+    if let Some(bench) = config.bench {
+        let stats = bench.sample(|| <A as ParseInput<D>>::try_parse_input(&raw_input, &params));
+        parsing_elapsed = stats.median;
+        parsing_stats = Some(stats);
+
+        if let Some(part1) = part1.as_mut().filter(|part| part.completed()) {
+            part1.set_stats(bench.sample(|| A::part1(&input)));
+        }
+        if let Some(part2) = part2.as_mut().filter(|part| part.completed()) {
+            part2.set_stats(bench.sample(|| A::part2(&input)));
+        }
+    }
+
+    Ok(DayReport {
+        day: D,
+        input: None,
+        timing: TimingData {
+            parsing: parsing_elapsed,
+            part1: part1.as_ref().and_then(DayResult::timing),
+            part2: part2.as_ref().and_then(DayResult::timing),
+        },
+        parsing_stats,
+        parsing_allocs,
+        parsing_failure: None,
+        part1,
+        part2,
+    })
+}
+
+struct SummaryRow {
+    day: u32,
+    input: Option<String>,
+    part1: Verdict,
+    part2: Verdict,
+    timing: Option<TimingData>,
+    /// Timings of the phases that ran to completion, `None` if parsing did not
+    completed_timing: Option<TimingData>,
+    /// Outputs of the parts that ran to completion
+    answers: [Option<String>; 2],
+}
+
+/// Verdicts and timings of every day of a year run
+#[derive(Default)]
+pub struct Summary {
+    rows: Vec<SummaryRow>,
+}
+
+impl Summary {
+    pub fn new() -> Self {
+        Summary { rows: Vec::new() }
+    }
+
+    pub fn push_report(&mut self, report: &DayReport) {
+        let (part1, part2) = report.verdicts();
+        self.rows.push(SummaryRow {
+            day: report.day,
+            input: report.input.clone(),
+            part1,
+            part2,
+            timing: Some(report.timing),
+            completed_timing: report.parsing_failure.is_none().then(|| {
+                let completed = |part: &Option<DayResult>| {
+                    part.as_ref()
+                        .filter(|part| part.completed())
+                        .and_then(DayResult::timing)
+                };
+                TimingData {
+                    part1: completed(&report.part1),
+                    part2: completed(&report.part2),
+                    ..report.timing
+                }
+            }),
+            answers: [&report.part1, &report.part2].map(|part| {
+                part.as_ref()
+                    .filter(|part| part.completed())
+                    .map(|part| part.output.clone())
+            }),
+        });
+    }
+
+    /// Records a day that did not produce any output at all
+    pub fn push_failed(&mut self, day: u32, verdict: Verdict) {
+        self.rows.push(SummaryRow {
+            day,
+            input: None,
+            part1: verdict.clone(),
+            part2: verdict,
+            timing: None,
+            completed_timing: None,
+            answers: [None, None],
+        });
+    }
+
+    pub fn has_regressions(&self) -> bool {
+        self.rows
+            .iter()
+            .any(|row| row.part1.is_regression() || row.part2.is_regression())
+    }
+
+    pub fn timings(&self) -> impl Iterator<Item = (u32, &TimingData)> + '_ {
+        self.rows
+            .iter()
+            .filter_map(|row| Some((row.day, row.timing.as_ref()?)))
+    }
+
+    /// Timings of the phases that ran to completion, leaving out those that panicked, timed
+    /// out or hit an invalid input
+    pub fn completed_timings(&self) -> impl Iterator<Item = (u32, &TimingData)> + '_ {
+        self.rows
+            .iter()
+            .filter_map(|row| Some((row.day, row.completed_timing.as_ref()?)))
+    }
+
+    /// Outputs of every part that ran to completion, as `(day, part, output)`
+    pub fn answers(&self) -> impl Iterator<Item = (u32, u32, &str)> + '_ {
+        self.rows.iter().flat_map(|row| {
+            [Part1, Part2]
+                .into_iter()
+                .zip(&row.answers)
+                .filter_map(|(part, answer)| Some((row.day, part, answer.as_deref()?)))
+        })
+    }
+
+    /// Sum of the time spent in the parts of every day
+    pub fn total(&self) -> Duration {
+        self.timings().map(|(_, timing)| timing.parts()).sum()
+    }
+
+    /// Sum of the time spent in every day, parsing included
+    pub fn total_with_parsing(&self) -> Duration {
+        self.timings()
+            .map(|(_, timing)| timing.parsing + timing.parts())
+            .sum()
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        let days: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| match &row.input {
+                Some(input) => format!("{} ({input})", row.day),
+                None => row.day.to_string(),
+            })
+            .collect();
+        let width = days.iter().map(String::len).max().unwrap_or(0).max(4);
+
+        writeln!(f, "{:>width$} | {:<30} | Part 2", "Day", "Part 1")?;
+        for (day, row) in days.iter().zip(&self.rows) {
+            writeln!(
+                f,
+                "{day:>width$} | {:<30} | {}",
+                row.part1.to_string(),
+                row.part2
+            )?;
+        }
+
+        let verdicts = || {
+            self.rows
+                .iter()
+                .flat_map(|row| [&row.part1, &row.part2])
+                .filter(|v| **v != Verdict::NotApplicable)
+        };
+        let correct = verdicts().filter(|v| **v == Verdict::Correct).count();
+        let regressions = verdicts().filter(|v| v.is_regression()).count();
+        write!(
+            f,
+            "{correct} correct, {regressions} failing, {} unchecked",
+            verdicts().count() - correct - regressions
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(results: &Results) -> Vec<(usize, Option<&str>, Option<&str>)> {
+        results
+            .days
+            .iter()
+            .map(|r| (r.day, r.part1.as_deref(), r.part2.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn record_inserts_days_in_order() {
+        let mut results = Results::single(5, Some("50".to_owned()), None);
+        assert_eq!(results.record(12, 1, "120", false), Recorded::Inserted);
+        assert_eq!(results.record(1, 2, "12", false), Recorded::Inserted);
+        assert_eq!(results.record(5, 2, "52", false), Recorded::Inserted);
+        assert_eq!(
+            answers(&results),
+            [
+                (1, None, Some("12")),
+                (5, Some("50"), Some("52")),
+                (12, Some("120"), None)
+            ]
+        );
+    }
+
+    #[test]
+    fn record_the_same_answer() {
+        let mut results = Results::single(5, Some("50".to_owned()), None);
+        assert_eq!(results.record(5, 1, "50", false), Recorded::Unchanged);
+        assert_eq!(results.record(5, 1, "50", true), Recorded::Unchanged);
+        assert_eq!(answers(&results), [(5, Some("50"), None)]);
+    }
+
+    #[test]
+    fn record_keeps_a_different_answer_without_force() {
+        let mut results = Results::single(5, Some("50".to_owned()), None);
+        assert_eq!(
+            results.record(5, 1, "51", false),
+            Recorded::Conflict {
+                expected: "50".to_owned()
+            }
+        );
+        assert_eq!(answers(&results), [(5, Some("50"), None)]);
+    }
+
+    #[test]
+    fn record_overwrites_a_different_answer_with_force() {
+        let mut results = Results::single(5, Some("50".to_owned()), None);
+        assert_eq!(
+            results.record(5, 1, "51", true),
+            Recorded::Overwritten {
+                previous: "50".to_owned()
+            }
+        );
+        assert_eq!(answers(&results), [(5, Some("51"), None)]);
     }
 }
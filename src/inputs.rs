@@ -0,0 +1,321 @@
+use std::fmt;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::helpers::{Results, RunConfig};
+
+/// Puzzle input of a day, with the expected answers it is checked against
+pub struct DayInput {
+    /// Name shown in the reports when this is not the real input
+    pub label: Option<String>,
+    pub input: String,
+    pub results: Option<Results>,
+    /// The only part this input applies to, for examples given for a single part
+    pub only_part: Option<u32>,
+    /// Puzzle parameters of the input, the day's defaults if `None`
+    pub params: Option<serde_json::Value>,
+}
+
+/// A day whose input is not there. Unlike other load errors, this is not a failure of
+/// the day, whose input was just not downloaded or written yet.
+#[derive(Debug)]
+pub struct MissingInput {
+    pub message: String,
+}
+
+impl fmt::Display for MissingInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for MissingInput {}
+
+/// Expected answers of an example, in a JSON file next to it. A part that is left out
+/// is not run on the example, unless both are left out.
+#[derive(Debug, Deserialize)]
+struct ExampleAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+    /// Puzzle parameters of the example, see `ParseInput::Params`
+    params: Option<serde_json::Value>,
+}
+
+impl DayInput {
+    /// The real input, checked against `results/{year}.json` if there is one
+    pub fn real(year: u32, day: u32) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(DayInput {
+            label: None,
+            input: read_input(&real_path(year, day))?,
+            results: Some(
+                Results::parse_or_default(year)
+                    .map_err(|err| format!("cannot load results/{year}.json: {err}"))?,
+            ),
+            only_part: None,
+            params: None,
+        })
+    }
+
+    /// Every example input of the day, see `example_paths`. Each example is checked
+    /// against the `.json` file of the same name, if there is one.
+    pub fn tests(year: u32, day: u32) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let dir = PathBuf::from(format!("./inputs/{year}"));
+        let paths = example_paths(year, day)?;
+        if paths.is_empty() {
+            return Err(MissingInput {
+                message: format!("no example input for day {day} in {}", dir.display()),
+            }
+            .into());
+        }
+
+        paths
+            .iter()
+            .map(|path| {
+                let label = path
+                    .strip_prefix(&dir)
+                    .unwrap_or(path)
+                    .with_extension("")
+                    .to_string_lossy()
+                    .trim_start_matches(&format!("day{day}_"))
+                    .to_owned();
+                Self::example(path, label, year, day)
+            })
+            .collect()
+    }
+
+    /// An example input, `label` naming it in the reports
+    pub fn example(
+        path: &Path,
+        label: String,
+        year: u32,
+        day: u32,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let input = read_input(&path.to_string_lossy())?;
+
+        let answers_path = path.with_extension("json");
+        let answers = if answers_path.exists() {
+            let answers = std::fs::read_to_string(&answers_path)
+                .map_err(|err| format!("cannot read {}: {err}", answers_path.display()))?;
+            Some(
+                serde_json::from_str::<ExampleAnswers>(&answers)
+                    .map_err(|err| format!("cannot load {}: {err}", answers_path.display()))?,
+            )
+        } else {
+            None
+        };
+
+        let (results, only_part, params) = match answers {
+            Some(ExampleAnswers {
+                part1,
+                part2,
+                params,
+            }) => {
+                let only_part = match (&part1, &part2) {
+                    // a day without a second part still reports it as not applicable
+                    (Some(_), None) if crate::has_part2(year, day) => Some(1),
+                    (None, Some(_)) => Some(2),
+                    _ => None,
+                };
+                let results = (part1.is_some() || part2.is_some())
+                    .then(|| Results::single(day as usize, part1, part2));
+                (results, only_part, params)
+            }
+            None => (None, None, None),
+        };
+
+        Ok(DayInput {
+            label: Some(label),
+            input,
+            results,
+            only_part,
+            params,
+        })
+    }
+
+    /// An input given on the command line, `-` meaning stdin. It is only checked if a
+    /// file of expected answers, in the format of `results/{year}.json`, is given too.
+    pub fn custom(path: &str, expected: Option<&str>) -> Result<Self, Box<dyn std::error::Error>> {
+        let input = if path == "-" {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|err| format!("cannot read stdin: {err}"))?;
+            input
+        } else {
+            read_input(path)?
+        };
+
+        let results = expected
+            .map(|path| {
+                Results::from_path(path).map_err(|err| format!("cannot load {path}: {err}"))
+            })
+            .transpose()?;
+
+        Ok(DayInput {
+            label: Some(if path == "-" { "stdin" } else { path }.to_owned()),
+            input,
+            results,
+            only_part: None,
+            params: None,
+        })
+    }
+
+    /// Configuration to run this input with, `None` if none of the selected parts apply to it
+    pub fn run_config(&self, config: &RunConfig) -> Option<RunConfig> {
+        match (self.only_part, config.part) {
+            (None, _) => Some(config.clone()),
+            (Some(only_part), None) => Some(RunConfig {
+                part: Some(only_part),
+                ..config.clone()
+            }),
+            (Some(only_part), Some(part)) => (only_part == part).then(|| config.clone()),
+        }
+    }
+}
+
+/// Path of the real input of a day
+pub fn real_path(year: u32, day: u32) -> String {
+    format!("./inputs/{year}/day{day}.txt")
+}
+
+/// Example inputs of a day, found in `inputs/{year}/`: `day{day}_test.txt`, numbered ones
+/// like `day{day}_test2.txt` or `day{day}_test_2.txt`, and the `.txt` files of a
+/// `day{day}_test/` directory.
+pub fn example_paths(year: u32, day: u32) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+    let dir = PathBuf::from(format!("./inputs/{year}"));
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let prefix = format!("day{day}_test");
+
+    let mut numbered = Vec::new();
+    for entry in
+        std::fs::read_dir(&dir).map_err(|err| format!("cannot read {}: {err}", dir.display()))?
+    {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        let Some(number) = name
+            .strip_prefix(&prefix)
+            .and_then(|rest| rest.strip_suffix(".txt"))
+        else {
+            continue;
+        };
+        let number = number.strip_prefix('_').unwrap_or(number);
+        if number.is_empty() {
+            numbered.push((0, name));
+        } else if let Ok(number) = number.parse::<u32>() {
+            numbered.push((number, name));
+        }
+    }
+    numbered.sort();
+    let mut paths: Vec<PathBuf> = numbered
+        .into_iter()
+        .map(|(_, name)| dir.join(name))
+        .collect();
+
+    let test_dir = dir.join(&prefix);
+    if test_dir.is_dir() {
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(&test_dir)
+            .map_err(|err| format!("cannot read {}: {err}", test_dir.display()))?
+        {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "txt") {
+                files.push(path);
+            }
+        }
+        files.sort();
+        paths.extend(files);
+    }
+    Ok(paths)
+}
+
+fn read_input(path: &str) -> Result<String, Box<dyn std::error::Error>> {
+    std::fs::read_to_string(path).map_err(|err| {
+        let message = format!("cannot read {path}: {err}");
+        if err.kind() == std::io::ErrorKind::NotFound {
+            MissingInput { message }.into()
+        } else {
+            message.into()
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::Verdict;
+
+    /// Writes an example and its sidecar to a directory of their own, returns the example
+    fn write_example(name: &str, input: &str, sidecar: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}-{name}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("day1_test.txt");
+        std::fs::write(&path, input).unwrap();
+        std::fs::write(path.with_extension("json"), sidecar).unwrap();
+        path
+    }
+
+    #[test]
+    fn sidecar_with_a_single_part() {
+        let path = write_example("single", "1\n", r#"{"part2": "5"}"#);
+        let input = DayInput::example(&path, "test".to_owned(), 2024, 1).unwrap();
+        assert_eq!(input.only_part, Some(2));
+        assert!(input.results.is_some());
+    }
+
+    #[test]
+    fn broken_sidecar_fails() {
+        let path = write_example("broken", "1\n", r#"{"part1": 5"#);
+        let err = DayInput::example(&path, "test".to_owned(), 2024, 1)
+            .err()
+            .expect("a broken sidecar is an error");
+        assert!(!err.is::<MissingInput>(), "{err}");
+        assert!(err.to_string().contains("day1_test.json"), "{err}");
+    }
+
+    #[test]
+    fn missing_example_is_missing_input() {
+        let err = read_input("./inputs/does-not-exist.txt").unwrap_err();
+        assert!(err.is::<MissingInput>(), "{err}");
+    }
+
+    #[cfg(feature = "aoc2023")]
+    #[test]
+    fn params_of_the_wrong_type_fail() {
+        let path = write_example(
+            "params",
+            "...\n.S.\n...\n",
+            r#"{"part1": "1", "params": {"steps": "six"}}"#,
+        );
+        let input = DayInput::example(&path, "test".to_owned(), 2023, 21).unwrap();
+        let err = crate::run_input(2023, 21, input, &RunConfig::default())
+            .err()
+            .expect("params that do not deserialize are an error");
+        assert!(err.to_string().contains("invalid params"), "{err}");
+    }
+
+    #[cfg(feature = "aoc2023")]
+    #[test]
+    fn first_part_only() {
+        let path = write_example("first", "1\n", r#"{"part1": "1"}"#);
+        let input = DayInput::example(&path, "test".to_owned(), 2023, 1).unwrap();
+        assert_eq!(input.only_part, Some(1));
+    }
+
+    #[cfg(feature = "aoc2023")]
+    #[test]
+    fn day_without_a_second_part() {
+        let path = Path::new("./inputs/2023/day25_test.txt");
+        let input = DayInput::example(path, "test".to_owned(), 2023, 25).unwrap();
+        assert_eq!(input.only_part, None);
+        let report = crate::run_input(2023, 25, input, &RunConfig::default())
+            .unwrap()
+            .expect("2023 day 25 has a solution");
+        assert_eq!(
+            report.verdicts(),
+            (Verdict::Correct, Verdict::NotApplicable)
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Solutions can recurse deeply, give workers the same stack as the main thread
+pub const WORKER_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Runs `f` on every item with `jobs` worker threads.
+///
+/// Results are handed to `consume` on the calling thread, in the order of `items`,
+/// as soon as every previous item is done. With a single job, everything runs on the
+/// calling thread.
+pub fn run_ordered<T, R, F, C>(items: &[T], jobs: usize, f: F, mut consume: C)
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+    C: FnMut(&T, R),
+{
+    if jobs <= 1 {
+        for item in items {
+            consume(item, f(item));
+        }
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let tx = tx.clone();
+            let (next, f) = (&next, &f);
+            thread::Builder::new()
+                .stack_size(WORKER_STACK_SIZE)
+                .spawn_scoped(scope, move || loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(index) else {
+                        break;
+                    };
+                    if tx.send((index, f(item))).is_err() {
+                        break;
+                    }
+                })
+                .expect("failed to spawn worker thread");
+        }
+        drop(tx);
+
+        let mut pending = BTreeMap::new();
+        let mut next_index = 0;
+        for (index, result) in rx {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&next_index) {
+                consume(&items[next_index], result);
+                next_index += 1;
+            }
+        }
+    });
+}
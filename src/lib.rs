@@ -0,0 +1,182 @@
+//! Advent of Code solutions.
+//!
+//! [`solve`] gives the answers of a day for an input. The solutions themselves are
+//! implementations of [`traits::ParseInput`] and [`traits::Solution`] on the type of their
+//! year, e.g. [`aoc2024::Aoc2024`], for typed access to the parsed input and each part:
+//!
+//! ```
+//! # #[cfg(feature = "aoc2024")] {
+//! use aoc::aoc2024::Aoc2024;
+//! use aoc::traits::days::Day1;
+//! use aoc::traits::{ParseInput, Solution};
+//!
+//! let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+//! assert_eq!(aoc::solve(2024, 1, input).unwrap(), ("11".to_owned(), "31".to_owned()));
+//!
+//! let lists = <Aoc2024 as ParseInput<Day1>>::parse_input(input);
+//! assert_eq!(<Aoc2024 as Solution<Day1>>::part1(&lists), 11);
+//! # }
+//! ```
+//!
+//! Each year is behind a cargo feature of the same name, all enabled by default.
+
+#![feature(iter_array_chunks)]
+#![feature(array_windows)]
+#![feature(get_many_mut)]
+#![feature(coroutines)]
+#![feature(iter_from_coroutine)]
+#![feature(associated_type_defaults)]
+
+use std::fmt;
+
+pub mod allocs;
+#[cfg(feature = "aoc2019")]
+pub mod aoc2019;
+#[cfg(feature = "aoc2021")]
+pub mod aoc2021;
+#[cfg(feature = "aoc2022")]
+pub mod aoc2022;
+#[cfg(feature = "aoc2023")]
+pub mod aoc2023;
+#[cfg(feature = "aoc2024")]
+pub mod aoc2024;
+pub mod calendar;
+pub mod grid;
+pub mod helpers;
+pub mod inputs;
+pub mod jobs;
+pub mod panics;
+pub mod parse;
+#[cfg(test)]
+mod tests;
+pub mod traits;
+mod watchdog;
+
+pub use calendar::days_in;
+use helpers::{DayReport, DayResult, RunConfig, Verdict};
+use inputs::DayInput;
+use panics::PanicReport;
+use parse::ParseError;
+
+/// Runs a day of a year on an input, `None` if the day has no solution
+pub type YearRunner =
+    fn(u32, &DayInput, &RunConfig) -> Option<Result<DayReport, Box<dyn std::error::Error>>>;
+
+// `YEARS` and `DISABLED_YEARS`, generated by build.rs from the `aoc{year}` directories
+include!(concat!(env!("OUT_DIR"), "/years.rs"));
+
+/// Runner of the days of `year`
+pub fn year_runner(year: u32) -> Result<YearRunner, Error> {
+    if DISABLED_YEARS.contains(&year) {
+        return Err(Error::Disabled { year });
+    }
+    YEARS
+        .iter()
+        .find(|(y, _, _)| *y == year)
+        .map(|(_, _, year_runner)| *year_runner)
+        .ok_or(Error::UndefinedYear { year })
+}
+
+/// Runs the real input of a day, or every example in test mode. `None` if the day has no
+/// solution.
+pub fn run_day(
+    year: u32,
+    day: u32,
+    test: bool,
+    config: &RunConfig,
+) -> Result<Option<Vec<DayReport>>, Box<dyn std::error::Error>> {
+    let inputs = if test {
+        DayInput::tests(year, day)?
+    } else {
+        vec![DayInput::real(year, day)?]
+    };
+
+    let mut reports = Vec::new();
+    for input in inputs {
+        let Some(config) = input.run_config(config) else {
+            continue;
+        };
+        match run_input(year, day, input, &config)? {
+            Some(report) => reports.push(report),
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(reports))
+}
+
+/// Runs a day on an input, `None` if the day has no solution
+pub fn run_input(
+    year: u32,
+    day: u32,
+    input: DayInput,
+    config: &RunConfig,
+) -> Result<Option<DayReport>, Box<dyn std::error::Error>> {
+    let year_runner = year_runner(year)?;
+    let report = year_runner(day, &input, config).transpose()?;
+    Ok(report.map(|report| DayReport {
+        input: input.label,
+        ..report
+    }))
+}
+
+/// Why a day could not be solved, see [`solve`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    UndefinedYear {
+        year: u32,
+    },
+    /// The year was left out of the build, see its cargo feature
+    Disabled {
+        year: u32,
+    },
+    NoSolution {
+        year: u32,
+        day: u32,
+    },
+    InvalidInput(ParseError),
+    Panicked(PanicReport),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Error::UndefinedYear { year } => write!(f, "undefined year {year}"),
+            Error::Disabled { year } => write!(
+                f,
+                "year {year} is not part of this build, enable its feature with `--features aoc{year}`"
+            ),
+            Error::NoSolution { year, day } => write!(f, "no solution for {year} day {day}"),
+            Error::InvalidInput(err) => write!(f, "invalid input at {err}"),
+            Error::Panicked(report) => write!(f, "panicked: {report}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Answers of both parts of a day for `input`. The answer of a part without one, like the
+/// second part of day 25, is empty.
+pub fn solve(year: u32, day: u32, input: &str) -> Result<(String, String), Error> {
+    let no_solution = || Error::NoSolution { year, day };
+    let year_runner = year_runner(year)?;
+    let input = DayInput {
+        label: None,
+        input: input.to_owned(),
+        results: None,
+        only_part: None,
+        params: None,
+    };
+    let report = year_runner(day, &input, &RunConfig::default())
+        .ok_or_else(no_solution)?
+        .expect("the default params are valid");
+
+    let answer = |part: Option<DayResult>| {
+        let part = part.expect("both parts run by default");
+        match part.verdict {
+            Verdict::InvalidInput(err) => Err(Error::InvalidInput(err)),
+            Verdict::Panicked(report) => Err(Error::Panicked(report)),
+            _ => Ok(part.output),
+        }
+    };
+    Ok((answer(report.part1)?, answer(report.part2)?))
+}
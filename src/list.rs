@@ -0,0 +1,127 @@
+use std::path::Path;
+
+use aoc::helpers::Results;
+use aoc::inputs;
+use aoc::traits::{Part1, Part2};
+
+/// What is available for each day of a year
+struct Coverage {
+    year: u32,
+    solutions: Vec<bool>,
+    inputs: Vec<bool>,
+    /// Number of example inputs
+    examples: Vec<usize>,
+    /// Parts with an expected answer
+    answers: Vec<(bool, bool)>,
+}
+
+impl Coverage {
+    fn new(year: u32, days: &[u32]) -> Result<Self, Box<dyn std::error::Error>> {
+        let results = Results::parse_or_default(year)
+            .map_err(|err| format!("cannot load results of {year}: {err}"))?;
+
+        let mut coverage = Coverage {
+            year,
+            solutions: Vec::new(),
+            inputs: Vec::new(),
+            examples: Vec::new(),
+            answers: Vec::new(),
+        };
+        for day in 1..=aoc::days_in(year) {
+            coverage.solutions.push(days.contains(&day));
+            coverage
+                .inputs
+                .push(Path::new(&inputs::real_path(year, day)).is_file());
+            coverage
+                .examples
+                .push(inputs::example_paths(year, day)?.len());
+            coverage
+                .answers
+                .push(
+                    results
+                        .results_for_day(day as usize)
+                        .map_or((false, false), |r| {
+                            // a day without a second part is complete with its first one
+                            let part1 = r.has_answer(Part1);
+                            let no_part2 = !aoc::has_part2(year, day);
+                            (part1, r.has_answer(Part2) || (no_part2 && part1))
+                        }),
+                );
+        }
+        Ok(coverage)
+    }
+
+    fn print(&self) {
+        let days = aoc::days_in(self.year);
+        print!("{:<10}", self.year);
+        for day in 1..=days {
+            print!("{day:>3}");
+        }
+        println!();
+
+        let row = |name: &str, cells: Vec<String>, count: usize| {
+            print!("{name:<10}");
+            for cell in cells {
+                print!("{cell:>3}");
+            }
+            println!("   {count:>2}/{days}");
+        };
+        let mark = |present: bool| if present { "#" } else { "." }.to_owned();
+
+        row(
+            "solution",
+            self.solutions.iter().map(|&s| mark(s)).collect(),
+            self.solutions.iter().filter(|&&s| s).count(),
+        );
+        row(
+            "input",
+            self.inputs.iter().map(|&i| mark(i)).collect(),
+            self.inputs.iter().filter(|&&i| i).count(),
+        );
+        row(
+            "examples",
+            self.examples
+                .iter()
+                .map(|&count| match count {
+                    0 => ".".to_owned(),
+                    1 => "#".to_owned(),
+                    count => count.to_string(),
+                })
+                .collect(),
+            self.examples.iter().filter(|&&count| count > 0).count(),
+        );
+        row(
+            "answers",
+            self.answers
+                .iter()
+                .map(|answers| match answers {
+                    (true, true) => "#".to_owned(),
+                    (true, false) => "1".to_owned(),
+                    (false, true) => "2".to_owned(),
+                    (false, false) => ".".to_owned(),
+                })
+                .collect(),
+            self.answers.iter().filter(|&&(p1, p2)| p1 && p2).count(),
+        );
+    }
+}
+
+/// Prints, for each year, the days that have a solution, an input, examples and expected
+/// answers
+pub fn print(years: &[u32]) -> Result<(), Box<dyn std::error::Error>> {
+    for (index, &year) in years.iter().enumerate() {
+        let (_, days, _) = aoc::YEARS
+            .iter()
+            .find(|(y, _, _)| *y == year)
+            .ok_or_else(|| format!("undefined year {year}"))?;
+
+        if index > 0 {
+            println!();
+        }
+        Coverage::new(year, days)?.print();
+    }
+
+    println!("\n# available, . missing; examples: their number if more than one;");
+    println!("answers: 1 or 2 when only that part has an expected answer");
+    Ok(())
+}
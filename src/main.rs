@@ -1,87 +1,528 @@
-#![feature(iter_array_chunks)]
-#![feature(array_windows)]
-#![feature(get_many_mut)]
-#![feature(coroutines)]
-#![feature(iter_from_coroutine)]
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
 
-use std::time::Duration;
+use aoc::helpers::{BenchConfig, DayReport, Recorded, Results, RunConfig, Summary, Verdict};
+use aoc::inputs::{DayInput, MissingInput};
+use aoc::{jobs, panics, run_day, run_input, DISABLED_YEARS, YEARS};
+use clap::{Parser, Subcommand};
 
-use clap::Parser;
+mod baseline;
+mod fetch;
+mod list;
+mod report;
+mod scaffold;
+mod selection;
+mod submit;
+#[cfg(target_os = "linux")]
+mod watch;
 
-mod aoc2019;
-mod aoc2021;
-mod aoc2022;
-mod aoc2023;
-mod aoc2024;
-mod grid;
-mod helpers;
-mod traits;
-
-use helpers::{Results, TimingData};
+use baseline::{Baseline, Thresholds};
+use report::{Format, Reporter};
+use selection::{DaySelection, YearSelection};
 
+/// Runs the solutions of the selected days, unless a command is given
 #[derive(Parser)]
-#[command(version = "1.0", author = "Paul C. <paulcacheux@gmail.com>")]
+#[command(
+    version = "1.0",
+    author = "Paul C. <paulcacheux@gmail.com>",
+    args_conflicts_with_subcommands = true
+)]
 struct Options {
-    /// Use test input
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Run and check the example inputs instead of the real ones
     #[arg(long)]
     test: bool,
-    /// Advent year
+    /// Advent year: `all` or a comma-separated list of years
     #[arg(long, default_value = "2024")]
-    year: u32,
+    year: YearSelection,
     /// Advent day
-    #[arg(long)]
+    #[arg(long, conflicts_with = "days")]
     day: Option<u32>,
+    /// Advent days to run, e.g. `1-5,12`
+    #[arg(long)]
+    days: Option<DaySelection>,
+    /// Read the input of the day from this file instead, `-` for stdin
+    #[arg(long, requires = "day", conflicts_with_all = ["test", "save_baseline", "compare_baseline"])]
+    input: Option<String>,
+    /// Check the answers for --input against this file, in the format of results/{year}.json
+    #[arg(long, requires = "input")]
+    expected: Option<String>,
+    /// Output format of the run report
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+    /// Benchmark mode: time parsing and each part over N runs
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    bench: Option<u32>,
+    /// Number of days to run in parallel. Day timings stay wall-clock times, so they grow
+    /// when more jobs than cores compete for the CPU.
+    #[arg(long, default_value = "1", value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: u32,
+    /// Give up on parsing or a part after this many seconds
+    #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+    /// Only run this part
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: Option<u32>,
+    /// Number of untimed runs before sampling in benchmark mode
+    #[arg(long, default_value = "3", requires = "bench")]
+    warmup: u32,
+    /// Save the timings of this run to results/{year}.timings.json
+    #[arg(long, conflicts_with = "test")]
+    save_baseline: bool,
+    /// Flag days that got slower than in results/{year}.timings.json
+    #[arg(long, conflicts_with = "test")]
+    compare_baseline: bool,
+    /// Write the answers of the parts missing from results/{year}.json into it
+    #[arg(long, conflicts_with_all = ["test", "input"])]
+    record: bool,
+    /// With --record, also replace answers that differ from the recorded ones
+    #[arg(long, requires = "record")]
+    force: bool,
+    /// Slowdown ratio from which a phase is flagged when comparing to the baseline
+    #[arg(long, default_value = "1.5", requires = "compare_baseline")]
+    regression_ratio: f64,
+    /// Phases faster than this many microseconds in the baseline are never flagged
+    #[arg(long, default_value = "100", requires = "compare_baseline")]
+    regression_floor_us: u64,
+    /// Run the day again whenever its input or source changes
+    #[arg(
+        long,
+        requires = "day",
+        conflicts_with_all = ["input", "record", "save_baseline", "compare_baseline"]
+    )]
+    watch: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Show which days have a solution, an input, examples and expected answers
+    List {
+        /// Advent year: `all` or a comma-separated list of years
+        #[arg(long, default_value = "all")]
+        year: YearSelection,
+    },
+    /// Create the solution file of a new day, with an empty example input
+    New {
+        /// Advent year
+        #[arg(long, default_value = "2024")]
+        year: u32,
+        /// Advent day
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        day: u32,
+    },
+    /// Download the released inputs that are missing from inputs/
+    Fetch {
+        /// Advent year: `all` or a comma-separated list of years
+        #[arg(long, default_value = "all")]
+        year: YearSelection,
+        /// Advent days to fetch, e.g. `1-5,12`
+        #[arg(long)]
+        days: Option<DaySelection>,
+        /// Server to fetch the inputs from
+        #[arg(long, default_value = fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Submit the answer of a part, computed from the real input
+    Submit {
+        /// Advent year
+        #[arg(long, default_value = "2024")]
+        year: u32,
+        /// Advent day
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+        /// Part to submit
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: u32,
+        /// Server to submit the answer to
+        #[arg(long, default_value = fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+}
+
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    let secs: f64 = s
+        .parse()
+        .map_err(|_| format!("invalid number of seconds `{s}`"))?;
+    Duration::try_from_secs_f64(secs).map_err(|err| err.to_string())
+}
+
+impl Options {
+    fn run_config(&self) -> RunConfig {
+        RunConfig {
+            bench: self.bench.map(|iterations| BenchConfig {
+                warmup: self.warmup as usize,
+                iterations: iterations as usize,
+            }),
+            part: self.part,
+            timeout: self.timeout,
+        }
+    }
+
+    fn thresholds(&self) -> Thresholds {
+        Thresholds {
+            ratio: self.regression_ratio,
+            floor: Duration::from_micros(self.regression_floor_us),
+        }
+    }
+}
+
+/// What came out of running a day, in a form that can be sent across threads
+enum DayOutcome {
+    Reports(Vec<DayReport>),
+    Failed(Verdict),
+    /// The input of the day is not there
+    Unavailable,
 }
 
-fn run_day(
+fn run_day_outcome(year: u32, day: u32, test: bool, config: &RunConfig) -> DayOutcome {
+    match panics::catch(|| run_day(year, day, test, config)) {
+        Ok(Ok(Some(reports))) => DayOutcome::Reports(reports),
+        Ok(Ok(None)) => DayOutcome::Failed(Verdict::NoSolution),
+        Ok(Err(err)) if err.is::<MissingInput>() => DayOutcome::Unavailable,
+        Ok(Err(err)) => DayOutcome::Failed(Verdict::LoadError(err.to_string())),
+        Err(panic) => DayOutcome::Failed(Verdict::Panicked(panic)),
+    }
+}
+
+fn run_all(
+    years: &[u32],
+    days: &DaySelection,
+    test: bool,
+    config: &RunConfig,
+    jobs: usize,
+    reporter: &mut Reporter,
+) -> Vec<(u32, Summary)> {
+    let all_days: Vec<(u32, u32)> = years
+        .iter()
+        .flat_map(|&year| days.days().map(move |day| (year, day)))
+        .filter(|&(year, day)| day <= aoc::days_in(year))
+        .collect();
+    // the summary of a year comes after its last selected day
+    let last_day = |year: u32| days.days().filter(|&day| day <= aoc::days_in(year)).last();
+
+    let mut summaries: Vec<(u32, Summary)> =
+        years.iter().map(|&year| (year, Summary::new())).collect();
+    let start = Instant::now();
+    jobs::run_ordered(
+        &all_days,
+        jobs,
+        |&(year, day)| run_day_outcome(year, day, test, config),
+        |&(year, day), outcome| {
+            let (_, summary) = summaries
+                .iter_mut()
+                .find(|(y, _)| *y == year)
+                .expect("every selected year has a summary");
+            match outcome {
+                DayOutcome::Reports(reports) => {
+                    for report in &reports {
+                        summary.push_report(report);
+                        reporter.day(year, report);
+                    }
+                }
+                DayOutcome::Failed(verdict) => {
+                    reporter.failed_day(year, day, &verdict);
+                    summary.push_failed(day, verdict);
+                }
+                DayOutcome::Unavailable => {}
+            }
+
+            if Some(day) == last_day(year) {
+                reporter.summary(year, summary);
+            }
+        },
+    );
+    let wall_clock = start.elapsed();
+
+    let summed: Duration = summaries
+        .iter()
+        .map(|(_, summary)| summary.total_with_parsing())
+        .sum();
+    reporter.info(format_args!(
+        "Wall-clock time: {wall_clock:?}, sum of the day wall-clock times: {summed:?}"
+    ));
+    summaries
+}
+
+fn run_single_day(
     year: u32,
     day: u32,
-    test: bool,
-) -> Result<Option<TimingData>, Box<dyn std::error::Error>> {
-    let (input_path, results) = if test {
-        (format!("./inputs/{year}/day{day}_test.txt"), None)
-    } else {
-        (
-            format!("./inputs/{year}/day{day}.txt"),
-            Some(Results::parse(year)?),
-        )
+    opts: &Options,
+    config: &RunConfig,
+    reporter: &mut Reporter,
+) -> Result<Summary, Box<dyn std::error::Error>> {
+    let reports = match &opts.input {
+        Some(path) => {
+            let input = DayInput::custom(path, opts.expected.as_deref())?;
+            run_input(year, day, input, config).map(|report| report.map(|report| vec![report]))
+        }
+        None => run_day(year, day, opts.test, config),
     };
-    let input = std::fs::read_to_string(input_path)?;
-
-    let year_runner = match year {
-        2019 => aoc2019::run_solution_for_day,
-        2021 => aoc2021::run_solution_for_day,
-        2022 => aoc2022::run_solution_for_day,
-        2023 => aoc2023::run_solution_for_day,
-        2024 => aoc2024::run_solution_for_day,
-        _ => panic!("undefined year {year}"),
+    match reports {
+        Ok(None) => Err(format!("no solution available for that day ({day})").into()),
+        Ok(Some(reports)) => {
+            let mut summary = Summary::new();
+            for report in &reports {
+                reporter.day(year, report);
+                summary.push_report(report);
+            }
+            Ok(summary)
+        }
+        Err(err) => Err(format!("Error loading day: {err}").into()),
+    }
+}
+
+/// Compares and/or saves the timings baseline of `year`, returns true if some days got slower
+fn check_baseline(
+    opts: &Options,
+    year: u32,
+    summary: &Summary,
+    reporter: &Reporter,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let mut slower = false;
+    if opts.compare_baseline {
+        let baseline =
+            Baseline::load(year).map_err(|err| format!("Error loading timings baseline: {err}"))?;
+        let slowdowns = baseline.compare(summary, &opts.thresholds());
+        if !slowdowns.is_empty() {
+            reporter.info(format_args!("\nSlower than {year} baseline:"));
+            for slowdown in &slowdowns {
+                reporter.info(format_args!("{slowdown}"));
+            }
+            slower = true;
+        }
+    }
+
+    if opts.save_baseline {
+        let mut baseline = Baseline::load_or_default(year)
+            .map_err(|err| format!("Error loading timings baseline: {err}"))?;
+        baseline.update(summary);
+        baseline
+            .save(year)
+            .map_err(|err| format!("Error saving timings baseline: {err}"))?;
+    }
+    Ok(slower)
+}
+
+/// Writes the answers of `summary` into results/{year}.json, see `--record`
+fn record_answers(
+    opts: &Options,
+    year: u32,
+    summary: &Summary,
+    reporter: &Reporter,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut results =
+        Results::parse_or_default(year).map_err(|err| format!("Error loading results: {err}"))?;
+
+    let mut changed = false;
+    for (day, part, answer) in summary.answers() {
+        match results.record(day as usize, part, answer, opts.force) {
+            Recorded::Inserted => {
+                reporter.info(format_args!("Recorded day {day}, part {part}: {answer}"));
+                changed = true;
+            }
+            Recorded::Overwritten { previous } => {
+                reporter.info(format_args!(
+                    "Recorded day {day}, part {part}: {answer} (was {previous})"
+                ));
+                changed = true;
+            }
+            Recorded::Conflict { expected } => reporter.info(format_args!(
+                "Not recording day {day}, part {part}: {answer} differs from {expected}, use --force to replace it"
+            )),
+            Recorded::Unchanged => {}
+        }
+    }
+
+    if changed {
+        results
+            .save(year)
+            .map_err(|err| format!("Error saving results: {err}"))?;
+    }
+    Ok(())
+}
+
+/// Computes the answer of a part and submits it, returns true unless it is correct
+fn submit_answer(
+    year: u32,
+    day: u32,
+    part: u32,
+    base_url: &str,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let config = RunConfig {
+        bench: None,
+        part: Some(part),
+        timeout: None,
     };
+    let report = run_day(year, day, false, &config)
+        .map_err(|err| format!("Error loading day: {err}"))?
+        .and_then(|reports| reports.into_iter().next())
+        .ok_or_else(|| format!("no solution available for that day ({day})"))?;
+    let result = match part {
+        1 => report.part1,
+        _ => report.part2,
+    }
+    .ok_or_else(|| format!("day {day}, part {part} did not run"))?;
 
-    Ok(year_runner(day, &input, results))
+    match result.verdict {
+        Verdict::MissingExpected => {}
+        Verdict::Correct => {
+            println!(
+                "Day {day}, Part {part}: {} is already recorded",
+                result.output
+            );
+            return Ok(false);
+        }
+        Verdict::Wrong { expected } => {
+            return Err(format!(
+                "not submitting day {day}, part {part}: {} differs from the recorded answer {expected}",
+                result.output
+            )
+            .into());
+        }
+        verdict => {
+            let reason = verdict.error().unwrap_or_else(|| verdict.to_string());
+            return Err(format!("day {day}, part {part} has no answer to submit: {reason}").into());
+        }
+    }
+
+    let outcome = submit::submit(year, day, part, &result.output, base_url)?;
+    Ok(outcome != submit::Outcome::Correct)
 }
 
-fn run_all(year: u32, test: bool) {
-    let mut total = Duration::ZERO;
-    for day in 1..=25 {
-        if let Ok(Some(elapsed)) = run_day(year, day, test) {
-            total += elapsed.part1 + elapsed.part2;
+/// Years of `selection` that are part of this build
+fn resolve_years(selection: &YearSelection) -> Result<Vec<u32>, Box<dyn std::error::Error>> {
+    if let YearSelection::Years(years) = selection {
+        if let Some(&year) = years.iter().find(|year| DISABLED_YEARS.contains(year)) {
+            return Err(aoc::Error::Disabled { year }.into());
         }
     }
-    println!("Total: {total:?}");
+    Ok(selection.resolve(&YEARS.map(|(year, _, _)| year))?)
 }
 
-fn main() {
+/// Runs a day again whenever its files change, see `--watch`. Only returns on errors.
+#[cfg(target_os = "linux")]
+fn watch_day(
+    year: u32,
+    day: u32,
+    test: bool,
+    config: &RunConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    watch::watch(year, day, test, config)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn watch_day(
+    _year: u32,
+    _day: u32,
+    _test: bool,
+    _config: &RunConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    Err("--watch relies on inotify and is only supported on Linux".into())
+}
+
+/// Runs the selected days, returns true if anything regressed
+fn try_main(opts: &Options) -> Result<bool, Box<dyn std::error::Error>> {
+    match &opts.command {
+        Some(Command::List { year }) => {
+            list::print(&resolve_years(year)?)?;
+            return Ok(false);
+        }
+        Some(Command::New { year, day }) => {
+            scaffold::new_day(*year, *day)?;
+            return Ok(false);
+        }
+        Some(Command::Fetch {
+            year,
+            days,
+            base_url,
+        }) => {
+            let days = days.clone().unwrap_or_else(DaySelection::all);
+            // inputs are fetched for the years left out of the build too
+            let mut known_years: Vec<u32> = YEARS
+                .map(|(year, _, _)| year)
+                .into_iter()
+                .chain(DISABLED_YEARS)
+                .collect();
+            known_years.sort_unstable();
+            fetch::fetch(&year.resolve(&known_years)?, &days, base_url)?;
+            return Ok(false);
+        }
+        Some(Command::Submit {
+            year,
+            day,
+            part,
+            base_url,
+        }) => return submit_answer(*year, *day, *part, base_url),
+        None => {}
+    }
+
+    let years = resolve_years(&opts.year)?;
+    if opts.input.is_some() && years.len() != 1 {
+        return Err("--input can only be used with a single year".into());
+    }
+    let days = opts.days.clone().unwrap_or_else(DaySelection::all);
+
+    let config = opts.run_config();
+    if opts.watch {
+        let (&[year], Some(day)) = (years.as_slice(), opts.day) else {
+            return Err("--watch can only be used with a single year".into());
+        };
+        watch_day(year, day, opts.test, &config)?;
+        return Ok(false);
+    }
+    let mut reporter = Reporter::new(opts.format);
+    let mut regressed = false;
+    let summaries = match opts.day {
+        Some(day) if years.len() == 1 => vec![(
+            years[0],
+            run_single_day(years[0], day, opts, &config, &mut reporter)?,
+        )],
+        Some(day) => run_all(
+            &years,
+            &DaySelection::single(day),
+            opts.test,
+            &config,
+            opts.jobs as usize,
+            &mut reporter,
+        ),
+        None => run_all(
+            &years,
+            &days,
+            opts.test,
+            &config,
+            opts.jobs as usize,
+            &mut reporter,
+        ),
+    };
+
+    let mut grand_total = Duration::ZERO;
+    for (year, summary) in &summaries {
+        regressed |= summary.has_regressions();
+        regressed |= check_baseline(opts, *year, summary, &reporter)?;
+        if opts.record {
+            record_answers(opts, *year, summary, &reporter)?;
+        }
+        grand_total += summary.total();
+    }
+
+    if years.len() > 1 {
+        reporter.info(format_args!("\nGrand total: {grand_total:?}"));
+    }
+    reporter.finish();
+
+    Ok(regressed)
+}
+
+fn main() -> ExitCode {
     let opts = Options::parse();
 
-    if let Some(day) = opts.day {
-        match run_day(opts.year, day, opts.test) {
-            Ok(None) => {
-                panic!("no solution available for that day ({day})")
-            }
-            Ok(Some(_)) => {}
-            Err(err) => panic!("Error loading day: {err}"),
+    match try_main(&opts) {
+        Ok(false) => ExitCode::SUCCESS,
+        Ok(true) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(2)
         }
-    } else {
-        run_all(opts.year, opts.test)
     }
 }
@@ -0,0 +1,80 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+/// Message and location of a caught panic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PanicReport {
+    pub message: String,
+    pub location: Option<String>,
+}
+
+impl PanicReport {
+    fn from_payload(payload: &(dyn Any + Send)) -> Self {
+        PanicReport {
+            message: payload_message(payload),
+            location: None,
+        }
+    }
+}
+
+impl fmt::Display for PanicReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match &self.location {
+            Some(location) => write!(f, "{} (at {location})", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_owned()
+    }
+}
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LAST_PANIC: RefCell<Option<PanicReport>> = const { RefCell::new(None) };
+}
+
+/// Wraps the default panic hook so that panics caught by `catch` are recorded
+/// instead of printed. Other panics are printed as usual.
+fn install_hook() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                LAST_PANIC.set(Some(PanicReport {
+                    message: payload_message(info.payload()),
+                    location: info.location().map(ToString::to_string),
+                }));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+/// Runs `f`, turning a panic into a `PanicReport`
+pub fn catch<R>(f: impl FnOnce() -> R) -> Result<R, PanicReport> {
+    install_hook();
+
+    let was_catching = CATCHING.replace(true);
+    LAST_PANIC.take();
+    let res = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(was_catching);
+
+    res.map_err(|payload| {
+        LAST_PANIC
+            .take()
+            .unwrap_or_else(|| PanicReport::from_payload(&*payload))
+    })
+}
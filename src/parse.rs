@@ -0,0 +1,161 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Error in a puzzle input, with the line it was found on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line number, from 1
+    pub line: usize,
+    /// Column of the offending text in the line, in characters from 1
+    pub column: usize,
+    /// Length of the offending text, in characters
+    pub length: usize,
+    /// The whole line
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// The same error, for a section of the input that starts after `lines` lines
+    pub fn after_lines(self, lines: usize) -> Self {
+        ParseError {
+            line: self.line + lines,
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "  {}", self.text)?;
+        write!(
+            f,
+            "  {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.length.max(1))
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A line of the input, see `lines`
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    /// Line number, from 1
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl Line<'_> {
+    /// Column of `part` in the line, 1 if it is not a slice of it
+    fn column(&self, part: &str) -> usize {
+        let offset = (part.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        self.text
+            .get(..offset)
+            .map_or(1, |before| before.chars().count() + 1)
+    }
+
+    /// Error about `part`, a slice of the line
+    pub fn error(&self, part: &str, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.number,
+            column: self.column(part),
+            length: part.chars().count(),
+            text: self.text.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Error about something missing at the end of the line
+    pub fn missing(&self, what: &str) -> ParseError {
+        self.error(&self.text[self.text.len()..], format!("missing {what}"))
+    }
+
+    /// Parses `part`, a slice of the line
+    pub fn parse<T>(&self, part: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        part.parse()
+            .map_err(|err| self.error(part, format!("invalid `{part}`: {err}")))
+    }
+}
+
+/// Lines of the input with their numbers, to locate parse errors
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(index, text)| Line {
+        number: index + 1,
+        text,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str) -> Line<'_> {
+        Line { number: 3, text }
+    }
+
+    #[test]
+    fn error_on_a_slice_of_the_line() {
+        let line = line("move 12 to x");
+        let err = line.error(&line.text[8..10], "bad destination");
+        assert_eq!((err.line, err.column, err.length), (3, 9, 2));
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 9: bad destination\n  move 12 to x\n          ^^"
+        );
+    }
+
+    #[test]
+    fn columns_count_characters_not_bytes() {
+        let line = line("é → x");
+        let x = &line.text[line.text.find('x').unwrap()..];
+        let err = line.error(x, "unexpected x");
+        assert_eq!((err.column, err.length), (5, 1));
+    }
+
+    #[test]
+    fn part_outside_of_the_line_points_at_its_start() {
+        let other = String::from("elsewhere");
+        let err = line("abc").error(&other, "not from the line");
+        assert_eq!((err.column, err.length), (1, 9));
+    }
+
+    #[test]
+    fn missing_points_past_the_end() {
+        let err = line("1,2").missing("a third number");
+        assert_eq!((err.column, err.length), (4, 0));
+        assert_eq!(err.message, "missing a third number");
+        assert!(err.to_string().ends_with("\n     ^"), "{err}");
+    }
+
+    #[test]
+    fn parse_reports_the_invalid_part() {
+        let line = line("12 abc");
+        assert_eq!(line.parse::<u32>(&line.text[..2]), Ok(12));
+        let err = line.parse::<u32>(&line.text[3..]).unwrap_err();
+        assert_eq!((err.column, err.length), (4, 3));
+        assert!(err.message.starts_with("invalid `abc`"), "{}", err.message);
+    }
+
+    #[test]
+    fn lines_are_numbered_from_one() {
+        let numbers: Vec<(usize, &str)> = lines("a\nb\n").map(|l| (l.number, l.text)).collect();
+        assert_eq!(numbers, [(1, "a"), (2, "b")]);
+    }
+
+    #[test]
+    fn after_lines_shifts_the_line() {
+        let err = line("x").error("x", "bad").after_lines(10);
+        assert_eq!((err.line, err.column), (13, 1));
+    }
+}
@@ -0,0 +1,251 @@
+use std::fmt;
+
+use aoc::allocs::AllocStats;
+use aoc::helpers::{DayReport, DayResult, Stats, Summary, Verdict};
+use clap::ValueEnum;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// Human readable output
+    #[default]
+    Text,
+    /// A JSON array with one record per day and input, printed once all days are done
+    Json,
+    /// One CSV row per day and input, with a header line. Timings are medians in benchmark mode,
+    /// panic messages are only available in JSON
+    Csv,
+}
+
+#[derive(Debug, Serialize)]
+struct Record {
+    year: u32,
+    day: u32,
+    /// Name of the input when it is not the real one, e.g. an example
+    input: Option<String>,
+    parsing_ns: Option<u64>,
+    part1_ns: Option<u64>,
+    part1_output: Option<String>,
+    part1_status: &'static str,
+    part2_ns: Option<u64>,
+    part2_output: Option<String>,
+    part2_status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1_error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2_error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bench: Option<BenchRecord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allocs: Option<AllocsRecord>,
+}
+
+/// Benchmark statistics of each phase, only present in benchmark mode
+#[derive(Debug, Serialize)]
+struct BenchRecord {
+    parsing: StatsRecord,
+    part1: Option<StatsRecord>,
+    part2: Option<StatsRecord>,
+}
+
+#[derive(Debug, Serialize)]
+struct StatsRecord {
+    samples: usize,
+    min_ns: u64,
+    median_ns: u64,
+    mean_ns: u64,
+    stddev_ns: u64,
+}
+
+impl From<&Stats> for StatsRecord {
+    fn from(stats: &Stats) -> Self {
+        StatsRecord {
+            samples: stats.samples,
+            min_ns: stats.min.as_nanos() as u64,
+            median_ns: stats.median.as_nanos() as u64,
+            mean_ns: stats.mean.as_nanos() as u64,
+            stddev_ns: stats.stddev.as_nanos() as u64,
+        }
+    }
+}
+
+/// Allocations of each phase, only present with the `alloc-stats` feature
+#[derive(Debug, Serialize)]
+struct AllocsRecord {
+    parsing: AllocRecord,
+    part1: Option<AllocRecord>,
+    part2: Option<AllocRecord>,
+}
+
+#[derive(Debug, Serialize)]
+struct AllocRecord {
+    count: u64,
+    bytes: u64,
+    peak_bytes: u64,
+}
+
+impl From<&AllocStats> for AllocRecord {
+    fn from(allocs: &AllocStats) -> Self {
+        AllocRecord {
+            count: allocs.count,
+            bytes: allocs.bytes,
+            peak_bytes: allocs.peak,
+        }
+    }
+}
+
+const CSV_HEADER: &str =
+    "year,day,input,parsing_ns,part1_ns,part1_output,part1_status,part2_ns,part2_output,part2_status";
+
+impl Record {
+    fn from_report(year: u32, report: &DayReport) -> Self {
+        let (part1, part2) = (report.part1.as_ref(), report.part2.as_ref());
+        let (part1_verdict, part2_verdict) = report.verdicts();
+        let part_stats = |part: Option<&DayResult>| part?.stats.as_ref().map(StatsRecord::from);
+        let part_allocs = |part: Option<&DayResult>| part?.allocs.as_ref().map(AllocRecord::from);
+
+        Record {
+            year,
+            day: report.day,
+            input: report.input.clone(),
+            parsing_ns: Some(report.timing.parsing.as_nanos() as u64),
+            part1_ns: part1
+                .and_then(DayResult::timing)
+                .map(|d| d.as_nanos() as u64),
+            part1_output: part1.map(|part| part.output.clone()),
+            part1_status: part1_verdict.status(),
+            part2_ns: part2
+                .and_then(DayResult::timing)
+                .map(|d| d.as_nanos() as u64),
+            part2_output: part2.map(|part| part.output.clone()),
+            part2_status: part2_verdict.status(),
+            part1_error: part1_verdict.error(),
+            part2_error: part2_verdict.error(),
+            bench: report.parsing_stats.as_ref().map(|parsing| BenchRecord {
+                parsing: parsing.into(),
+                part1: part_stats(part1),
+                part2: part_stats(part2),
+            }),
+            allocs: report.parsing_allocs.as_ref().map(|parsing| AllocsRecord {
+                parsing: parsing.into(),
+                part1: part_allocs(part1),
+                part2: part_allocs(part2),
+            }),
+        }
+    }
+
+    fn failed(year: u32, day: u32, verdict: &Verdict) -> Self {
+        Record {
+            year,
+            day,
+            input: None,
+            parsing_ns: None,
+            part1_ns: None,
+            part1_output: None,
+            part1_status: verdict.status(),
+            part2_ns: None,
+            part2_output: None,
+            part2_status: verdict.status(),
+            part1_error: verdict.error(),
+            part2_error: verdict.error(),
+            bench: None,
+            allocs: None,
+        }
+    }
+
+    fn write_csv(&self) {
+        let cells = [
+            self.year.to_string(),
+            self.day.to_string(),
+            csv_escape(self.input.as_deref().unwrap_or_default()),
+            csv_opt(self.parsing_ns),
+            csv_opt(self.part1_ns),
+            csv_escape(self.part1_output.as_deref().unwrap_or_default()),
+            self.part1_status.to_owned(),
+            csv_opt(self.part2_ns),
+            csv_escape(self.part2_output.as_deref().unwrap_or_default()),
+            self.part2_status.to_owned(),
+        ];
+        println!("{}", cells.join(","));
+    }
+}
+
+fn csv_opt<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+/// Prints day reports in the requested format.
+///
+/// In the machine-readable formats, stdout only contains the records: everything
+/// meant for humans (totals, summary table) goes to stderr.
+pub struct Reporter {
+    format: Format,
+    records: Vec<Record>,
+}
+
+impl Reporter {
+    pub fn new(format: Format) -> Self {
+        if format == Format::Csv {
+            println!("{CSV_HEADER}");
+        }
+
+        Reporter {
+            format,
+            records: Vec::new(),
+        }
+    }
+
+    pub fn day(&mut self, year: u32, report: &DayReport) {
+        let record = Record::from_report(year, report);
+        match self.format {
+            Format::Text => println!("{report}"),
+            Format::Json => self.records.push(record),
+            Format::Csv => record.write_csv(),
+        }
+    }
+
+    /// Records a day that did not produce any output at all
+    pub fn failed_day(&mut self, year: u32, day: u32, verdict: &Verdict) {
+        let record = Record::failed(year, day, verdict);
+        match self.format {
+            Format::Text => {
+                if let Some(error) = verdict.error() {
+                    println!("Day {day}: {error}");
+                }
+            }
+            Format::Json => self.records.push(record),
+            Format::Csv => record.write_csv(),
+        }
+    }
+
+    pub fn info(&self, args: fmt::Arguments<'_>) {
+        match self.format {
+            Format::Text => println!("{args}"),
+            Format::Json | Format::Csv => eprintln!("{args}"),
+        }
+    }
+
+    pub fn summary(&self, year: u32, summary: &Summary) {
+        self.info(format_args!(
+            "Total {year}: {:?}\n\n{summary}\n",
+            summary.total()
+        ));
+    }
+
+    pub fn finish(self) {
+        if self.format == Format::Json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&self.records).expect("records are serializable")
+            );
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use std::path::Path;
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs");
+const YEAR_TEMPLATE: &str = include_str!("../templates/year.rs");
+
+/// Name of the module declared by `line`, if it is a `mod` declaration
+fn declared_module(line: &str) -> Option<&str> {
+    line.trim()
+        .trim_start_matches("pub ")
+        .strip_prefix("mod ")?
+        .strip_suffix(';')
+}
+
+/// Name of the module declared by the last line of `declaration`, after its attributes
+fn module_name(declaration: &str) -> &str {
+    declaration
+        .lines()
+        .last()
+        .and_then(declared_module)
+        .expect("a module declaration")
+}
+
+/// Adds `declaration` to the `mod` declarations of `source`, where rustfmt would sort it
+fn insert_module(source: &str, declaration: &str) -> String {
+    let name = module_name(declaration);
+    let mut lines: Vec<&str> = source.lines().collect();
+
+    let modules: Vec<(usize, &str)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, declared_module(line)?)))
+        .collect();
+    let index = match modules.iter().find(|(_, module)| *module > name) {
+        // before the attributes of the next module
+        Some((index, _)) => {
+            let mut index = *index;
+            while index > 0 && lines[index - 1].trim_start().starts_with("#[") {
+                index -= 1;
+            }
+            index
+        }
+        None => match modules.last() {
+            Some((index, _)) => index + 1,
+            None => {
+                // no module yet, start a new group after the year struct
+                let index = lines
+                    .iter()
+                    .position(|line| line.starts_with("pub struct"))
+                    .map_or(lines.len(), |index| index + 1);
+                lines.insert(index, "");
+                index + 1
+            }
+        },
+    };
+    lines.insert(index, declaration);
+
+    let mut source = lines.join("\n");
+    source.push('\n');
+    source
+}
+
+fn create(path: &Path, content: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|err| format!("cannot create {}: {err}", dir.display()))?;
+    }
+    std::fs::write(path, content)
+        .map_err(|err| format!("cannot write {}: {err}", path.display()))?;
+    println!("Created {}", path.display());
+    Ok(())
+}
+
+fn add_module(path: &Path, declaration: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let source = std::fs::read_to_string(path)
+        .map_err(|err| format!("cannot read {}: {err}", path.display()))?;
+    if source
+        .lines()
+        .any(|line| declared_module(line) == Some(module_name(declaration)))
+    {
+        return Ok(());
+    }
+    std::fs::write(path, insert_module(&source, declaration))
+        .map_err(|err| format!("cannot write {}: {err}", path.display()))?;
+    println!(
+        "Added `{}` to {}",
+        declaration.replace('\n', " "),
+        path.display()
+    );
+    Ok(())
+}
+
+/// Adds the cargo feature `feature` of a year to the manifest `source`, after the other
+/// years and in the default features
+fn insert_feature(source: &str, feature: &str) -> Result<String, String> {
+    let is_year_feature = |line: &str| {
+        line.split_once(" =").is_some_and(|(name, _)| {
+            name.strip_prefix("aoc")
+                .is_some_and(|year| !year.is_empty() && year.bytes().all(|b| b.is_ascii_digit()))
+        })
+    };
+
+    let mut lines: Vec<String> = source.lines().map(str::to_owned).collect();
+    let default = lines
+        .iter()
+        .position(|line| line.starts_with("default = ["))
+        .ok_or("no default features")?;
+    let close = lines[default]
+        .rfind(']')
+        .ok_or_else(|| format!("cannot add {feature} to the default features"))?;
+    lines[default].insert_str(close, &format!(", \"{feature}\""));
+    let last_year = lines
+        .iter()
+        .rposition(|line| is_year_feature(line))
+        .unwrap_or(default);
+    lines.insert(last_year + 1, format!("{feature} = []"));
+
+    let mut source = lines.join("\n");
+    source.push('\n');
+    Ok(source)
+}
+
+/// Declares the cargo feature of a new year in `manifest`, enabled by default
+fn add_feature(manifest: &Path, year: u32) -> Result<(), Box<dyn std::error::Error>> {
+    let source = std::fs::read_to_string(manifest)
+        .map_err(|err| format!("cannot read {}: {err}", manifest.display()))?;
+    let feature = format!("aoc{year}");
+    let source = insert_feature(&source, &feature)
+        .map_err(|err| format!("{err} in {}", manifest.display()))?;
+    std::fs::write(manifest, source)
+        .map_err(|err| format!("cannot write {}: {err}", manifest.display()))?;
+    println!("Added the {feature} feature to {}", manifest.display());
+    Ok(())
+}
+
+/// Creates the solution file of a day from the template, declares it in its year module,
+/// creating the year and its cargo feature if needed, and adds an empty example input.
+/// Nothing is written if one of the files already exists.
+pub fn new_day(year: u32, day: u32) -> Result<(), Box<dyn std::error::Error>> {
+    let days = aoc::days_in(year);
+    if !(1..=days).contains(&day) {
+        return Err(format!("invalid day {day}, the {year} event has {days} days").into());
+    }
+
+    let year_dir = format!("./src/aoc{year}");
+    let year_module = Path::new(&year_dir).join("mod.rs");
+    let day_file = Path::new(&year_dir).join(format!("day{day}.rs"));
+    let test_input = format!("./inputs/{year}/day{day}_test.txt");
+    let test_input = Path::new(&test_input);
+
+    for path in [day_file.as_path(), test_input] {
+        if path.exists() {
+            return Err(format!("{} already exists", path.display()).into());
+        }
+    }
+
+    let new_year = !year_module.exists();
+    if new_year {
+        create(
+            &year_module,
+            &YEAR_TEMPLATE.replace("2019", &year.to_string()),
+        )?;
+        add_module(
+            Path::new("./src/lib.rs"),
+            &format!("#[cfg(feature = \"aoc{year}\")]\npub mod aoc{year};"),
+        )?;
+        add_feature(Path::new("./Cargo.toml"), year)?;
+    }
+
+    let day_source = DAY_TEMPLATE
+        .replace("Day1", &format!("Day{day}"))
+        .replace("2019", &year.to_string());
+    create(&day_file, &day_source)?;
+    add_module(&year_module, &format!("pub mod day{day};"))?;
+    create(test_input, "")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR: &str = "use crate::traits::days::*;\n\npub struct Aoc2024;\n\npub mod day1;\npub mod day12;\npub mod day3;\n\ninclude!(\"aoc2024.rs\");\n";
+
+    #[test]
+    fn insert_module_in_order() {
+        assert_eq!(
+            insert_module(YEAR, "pub mod day2;"),
+            YEAR.replace("pub mod day3;", "pub mod day2;\npub mod day3;")
+        );
+        assert_eq!(
+            insert_module(YEAR, "pub mod day10;"),
+            YEAR.replace("pub mod day12;", "pub mod day10;\npub mod day12;")
+        );
+        assert_eq!(
+            insert_module(YEAR, "pub mod day4;"),
+            YEAR.replace("pub mod day3;", "pub mod day3;\npub mod day4;")
+        );
+    }
+
+    #[test]
+    fn insert_module_in_an_empty_year() {
+        let year =
+            "use crate::traits::days::*;\n\npub struct Aoc2025;\n\ninclude!(\"aoc2025.rs\");\n";
+        assert_eq!(
+            insert_module(year, "pub mod day1;"),
+            year.replace("Aoc2025;\n", "Aoc2025;\n\npub mod day1;\n")
+        );
+    }
+
+    #[test]
+    fn insert_module_before_attributes() {
+        let lib =
+            "pub mod allocs;\n#[cfg(feature = \"aoc2023\")]\npub mod aoc2023;\npub mod grid;\n";
+        assert_eq!(
+            insert_module(lib, "#[cfg(feature = \"aoc2022\")]\npub mod aoc2022;"),
+            "pub mod allocs;\n#[cfg(feature = \"aoc2022\")]\npub mod aoc2022;\n#[cfg(feature = \"aoc2023\")]\npub mod aoc2023;\npub mod grid;\n"
+        );
+        assert_eq!(
+            insert_module(lib, "#[cfg(feature = \"aoc2024\")]\npub mod aoc2024;"),
+            "pub mod allocs;\n#[cfg(feature = \"aoc2023\")]\npub mod aoc2023;\n#[cfg(feature = \"aoc2024\")]\npub mod aoc2024;\npub mod grid;\n"
+        );
+    }
+
+    #[test]
+    fn insert_feature_after_the_last_year() {
+        let manifest = "[features]\ndefault = [\"aoc2023\", \"aoc2024\"]\naoc2023 = [\"dep:regex\"]\naoc2024 = []\n# allocations\nalloc-stats = []\n";
+        assert_eq!(
+            insert_feature(manifest, "aoc2025").unwrap(),
+            "[features]\ndefault = [\"aoc2023\", \"aoc2024\", \"aoc2025\"]\naoc2023 = [\"dep:regex\"]\naoc2024 = []\naoc2025 = []\n# allocations\nalloc-stats = []\n"
+        );
+    }
+
+    #[test]
+    fn insert_feature_without_default_features() {
+        assert_eq!(
+            insert_feature("[features]\naoc2024 = []\n", "aoc2025"),
+            Err("no default features".to_owned())
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use std::str::FromStr;
+
+use aoc::calendar::MAX_DAYS;
+
+/// Years selected on the command line: `all` or a comma-separated list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum YearSelection {
+    All,
+    Years(Vec<u32>),
+}
+
+impl YearSelection {
+    /// Selected years, in the order of `available` for `all`
+    pub fn resolve(&self, available: &[u32]) -> Result<Vec<u32>, String> {
+        match self {
+            YearSelection::All => Ok(available.to_vec()),
+            YearSelection::Years(years) => {
+                if let Some(year) = years.iter().find(|y| !available.contains(y)) {
+                    return Err(format!("undefined year {year}"));
+                }
+                Ok(years.clone())
+            }
+        }
+    }
+}
+
+impl FromStr for YearSelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(YearSelection::All);
+        }
+
+        let mut years = Vec::new();
+        for year in s.split(',') {
+            let year = year
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid year `{year}`"))?;
+            if !years.contains(&year) {
+                years.push(year);
+            }
+        }
+        Ok(YearSelection::Years(years))
+    }
+}
+
+/// Days selected on the command line, e.g. `1-5,12`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection(Vec<u32>);
+
+impl DaySelection {
+    /// Every day an event can have, see `aoc::days_in` for those of a year
+    pub fn all() -> Self {
+        DaySelection((1..=MAX_DAYS).collect())
+    }
+
+    pub fn single(day: u32) -> Self {
+        DaySelection(vec![day])
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = u32> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day: &str| -> Result<u32, String> {
+            match day.trim().parse() {
+                Ok(day @ 1..=MAX_DAYS) => Ok(day),
+                _ => Err(format!(
+                    "invalid day `{day}`, expected a number in 1..={MAX_DAYS}"
+                )),
+            }
+        };
+
+        let mut days = Vec::new();
+        for range in s.split(',') {
+            if let Some((start, end)) = range.split_once('-') {
+                let (start, end) = (parse_day(start)?, parse_day(end)?);
+                if start > end {
+                    return Err(format!("invalid day range `{range}`"));
+                }
+                days.extend(start..=end);
+            } else {
+                days.push(parse_day(range)?);
+            }
+        }
+        days.sort_unstable();
+        days.dedup();
+        Ok(DaySelection(days))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn days(s: &str) -> Result<Vec<u32>, String> {
+        s.parse::<DaySelection>()
+            .map(|selection| selection.days().collect())
+    }
+
+    #[test]
+    fn days_and_ranges() {
+        assert_eq!(days("3"), Ok(vec![3]));
+        assert_eq!(days("1-3, 12"), Ok(vec![1, 2, 3, 12]));
+        assert_eq!(days("25-25"), Ok(vec![25]));
+    }
+
+    #[test]
+    fn days_are_sorted_without_duplicates() {
+        assert_eq!(days("12,2-4,3,12"), Ok(vec![2, 3, 4, 12]));
+    }
+
+    #[test]
+    fn invalid_days() {
+        assert_eq!(days("5-1"), Err("invalid day range `5-1`".to_owned()));
+        assert!(days("0").is_err());
+        assert!(days("26").is_err());
+        assert!(days("1-26").is_err());
+        assert!(days("x").is_err());
+        assert!(days("").is_err());
+    }
+
+    #[test]
+    fn all_years() {
+        assert_eq!("all".parse(), Ok(YearSelection::All));
+        assert_eq!(
+            YearSelection::All.resolve(&[2022, 2023]),
+            Ok(vec![2022, 2023])
+        );
+    }
+
+    #[test]
+    fn listed_years() {
+        let selection: YearSelection = "2023, 2022,2023".parse().unwrap();
+        assert_eq!(selection, YearSelection::Years(vec![2023, 2022]));
+        assert_eq!(selection.resolve(&[2022, 2023]), Ok(vec![2023, 2022]));
+        assert!("2023,twenty".parse::<YearSelection>().is_err());
+    }
+
+    #[test]
+    fn unknown_years() {
+        let selection = YearSelection::Years(vec![2022, 2015]);
+        assert_eq!(
+            selection.resolve(&[2022, 2023]),
+            Err("undefined year 2015".to_owned())
+        );
+    }
+}
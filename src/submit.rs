@@ -0,0 +1,371 @@
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aoc::helpers::{Recorded, Results};
+use serde::{Deserialize, Serialize};
+
+use crate::fetch;
+
+const LOG_PATH: &str = "./results/submissions.jsonl";
+
+/// What the site answered to a submission
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint
+    Wrong,
+    /// Submitted too soon after the previous answer, nothing was checked
+    Wait {
+        /// How long the site asks to wait, if it could be read
+        seconds: Option<u64>,
+    },
+    /// The part was already solved, nothing was checked
+    AlreadySolved,
+    /// The response could not be understood
+    Unknown,
+}
+
+impl Outcome {
+    fn is_wrong(self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::Wait {
+                seconds: Some(seconds),
+            } => write!(f, "too soon, wait {}m {}s", seconds / 60, seconds % 60),
+            Outcome::Wait { seconds: None } => write!(f, "too soon"),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unknown => write!(f, "unknown response"),
+        }
+    }
+}
+
+/// One line of `results/submissions.jsonl`
+#[derive(Debug, Serialize, Deserialize)]
+struct Submission {
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: String,
+    outcome: Outcome,
+    /// Text of the response, e.g. how long to wait
+    message: String,
+    /// Seconds since the Unix epoch
+    timestamp: u64,
+}
+
+impl Submission {
+    /// Why `answer` is known to be wrong from this earlier submission, if it is
+    fn rules_out(&self, answer: &str) -> Option<String> {
+        if !self.outcome.is_wrong() {
+            return None;
+        }
+        if self.answer == answer {
+            return Some(format!(
+                "{answer} was already submitted and is {}",
+                self.outcome
+            ));
+        }
+
+        let (Ok(answer), Ok(previous)) = (answer.parse::<i128>(), self.answer.parse::<i128>())
+        else {
+            return None;
+        };
+        match self.outcome {
+            Outcome::TooHigh if answer >= previous => {
+                Some(format!("{answer} is too high, {previous} already was"))
+            }
+            Outcome::TooLow if answer <= previous => {
+                Some(format!("{answer} is too low, {previous} already was"))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn load_log() -> Result<Vec<Submission>, Box<dyn std::error::Error>> {
+    let log = match std::fs::read_to_string(LOG_PATH) {
+        Ok(log) => log,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(format!("cannot read {LOG_PATH}: {err}").into()),
+    };
+    log.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line)
+                .map_err(|err| format!("cannot load {LOG_PATH}, line {}: {err}", index + 1).into())
+        })
+        .collect()
+}
+
+fn append_log(submission: &Submission) -> Result<(), Box<dyn std::error::Error>> {
+    let mut log = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(LOG_PATH)
+        .map_err(|err| format!("cannot open {LOG_PATH}: {err}"))?;
+    writeln!(log, "{}", serde_json::to_string(submission)?)
+        .map_err(|err| format!("cannot write {LOG_PATH}: {err}"))?;
+    Ok(())
+}
+
+/// Text of the `<article>` of a response page, without its markup
+fn response_message(body: &str) -> String {
+    let article = body
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(body, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Seconds left to wait from a response like "You have 1m 30s left to wait"
+fn parse_wait(message: &str) -> Option<u64> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (time, _) = rest.split_once(" left to wait")?;
+    time.split_whitespace()
+        .map(|part| {
+            let (number, factor) = [("h", 3600), ("m", 60), ("s", 1)]
+                .into_iter()
+                .find_map(|(unit, factor)| Some((part.strip_suffix(unit)?, factor)))?;
+            Some(number.parse::<u64>().ok()? * factor)
+        })
+        .sum()
+}
+
+fn parse_outcome(message: &str) -> Outcome {
+    if message.contains("That's the right answer") {
+        Outcome::Correct
+    } else if message.contains("your answer is too high") {
+        Outcome::TooHigh
+    } else if message.contains("your answer is too low") {
+        Outcome::TooLow
+    } else if message.contains("That's not the right answer") {
+        Outcome::Wrong
+    } else if message.contains("You gave an answer too recently") {
+        Outcome::Wait {
+            seconds: parse_wait(message),
+        }
+    } else if message.contains("You don't seem to be solving the right level") {
+        Outcome::AlreadySolved
+    } else {
+        Outcome::Unknown
+    }
+}
+
+fn post_answer(
+    base_url: &str,
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: &str,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let url = format!("{}/{year}/day/{day}/answer", base_url.trim_end_matches('/'));
+    let response = match fetch::agent()
+        .post(&url)
+        .set("Cookie", &format!("session={}", fetch::session()?))
+        .send_form(&[("level", &part.to_string()), ("answer", answer)])
+    {
+        Ok(response) => response,
+        Err(ureq::Error::Status(status, response)) => {
+            return Err(format!("{url} returned {status} {}", response.status_text()).into());
+        }
+        Err(err) => return Err(format!("cannot post to {url}: {err}").into()),
+    };
+    if response.status() != 200 {
+        return Err(format!(
+            "{url} returned {} {}",
+            response.status(),
+            response.status_text()
+        )
+        .into());
+    }
+    response
+        .into_string()
+        .map_err(|err| format!("cannot read the response of {url}: {err}").into())
+}
+
+/// Submits the answer of a part, unless an earlier submission shows it is wrong. Every
+/// response is logged to results/submissions.jsonl, and a correct answer is recorded in
+/// results/{year}.json.
+pub fn submit(
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: &str,
+    base_url: &str,
+) -> Result<Outcome, Box<dyn std::error::Error>> {
+    let log = load_log()?;
+    if let Some(reason) = log
+        .iter()
+        .filter(|s| (s.year, s.day, s.part) == (year, day, part))
+        .find_map(|s| s.rules_out(answer))
+    {
+        return Err(format!("not submitting day {day}, part {part}: {reason}").into());
+    }
+
+    let body = post_answer(base_url, year, day, part, answer)?;
+    let message = response_message(&body);
+    let outcome = parse_outcome(&message);
+    println!("Day {day}, Part {part}: {answer} is {outcome}");
+    if matches!(outcome, Outcome::Wait { seconds: None } | Outcome::Unknown) {
+        println!("{message}");
+    }
+
+    append_log(&Submission {
+        year,
+        day,
+        part,
+        answer: answer.to_owned(),
+        outcome,
+        message,
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs()),
+    })?;
+
+    if outcome == Outcome::Correct {
+        let mut results = Results::parse_or_default(year)
+            .map_err(|err| format!("Error loading results: {err}"))?;
+        if let Recorded::Inserted | Recorded::Overwritten { .. } =
+            results.record(day as usize, part, answer, true)
+        {
+            results
+                .save(year)
+                .map_err(|err| format!("Error saving results: {err}"))?;
+            println!("Recorded day {day}, part {part}: {answer}");
+        }
+    }
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A response page of the site around the `<article>` holding its answer
+    fn page(article: &str) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html lang=\"en-us\">\n<head>\n<title>Day 1 - Advent of Code 2024</title>\n</head>\n<body>\n<header><h1 class=\"title-global\"><a href=\"/\">Advent of Code</a></h1></header>\n<main>\n<article><p>{article}</p></article>\n</main>\n</body>\n</html>\n"
+        )
+    }
+
+    fn outcome(article: &str) -> Outcome {
+        parse_outcome(&response_message(&page(article)))
+    }
+
+    fn submission(answer: &str, outcome: Outcome) -> Submission {
+        Submission {
+            year: 2024,
+            day: 1,
+            part: 1,
+            answer: answer.to_owned(),
+            outcome,
+            message: String::new(),
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn right_answer() {
+        let article = "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to finding the Chief Historian. <a href=\"/2024/day/1#part2\">[Continue to Part Two]</a>";
+        assert_eq!(
+            response_message(&page(article)),
+            "That's the right answer! You are one gold star closer to finding the Chief Historian. [Continue to Part Two]"
+        );
+        assert_eq!(outcome(article), Outcome::Correct);
+    }
+
+    #[test]
+    fn too_high() {
+        let article = "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2024/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a>";
+        assert_eq!(outcome(article), Outcome::TooHigh);
+    }
+
+    #[test]
+    fn too_low() {
+        let article = "That's not the right answer; your answer is too low.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2024/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a>";
+        assert_eq!(outcome(article), Outcome::TooLow);
+    }
+
+    #[test]
+    fn wrong_without_hint() {
+        let article = "That's not the right answer.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2024/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a>";
+        assert_eq!(outcome(article), Outcome::Wrong);
+    }
+
+    #[test]
+    fn wait() {
+        let article = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 12s left to wait. <a href=\"/2024/day/1\">[Return to Day 1]</a>";
+        assert_eq!(outcome(article), Outcome::Wait { seconds: Some(252) });
+
+        let article = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait. <a href=\"/2024/day/1\">[Return to Day 1]</a>";
+        assert_eq!(outcome(article), Outcome::Wait { seconds: Some(34) });
+    }
+
+    #[test]
+    fn already_solved() {
+        let article = "You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2024/day/1\">[Return to Day 1]</a>";
+        assert_eq!(outcome(article), Outcome::AlreadySolved);
+    }
+
+    #[test]
+    fn unknown_response() {
+        assert_eq!(outcome("Please log in."), Outcome::Unknown);
+    }
+
+    #[test]
+    fn rules_out_known_wrong_answers() {
+        let wrong = submission("1234", Outcome::Wrong);
+        assert!(wrong.rules_out("1234").is_some());
+        assert!(wrong.rules_out("1235").is_none());
+
+        let correct = submission("1234", Outcome::Correct);
+        assert!(correct.rules_out("1234").is_none());
+
+        let wait = submission("1234", Outcome::Wait { seconds: Some(60) });
+        assert!(wait.rules_out("1234").is_none());
+    }
+
+    #[test]
+    fn rules_out_beyond_too_high() {
+        let too_high = submission("1000", Outcome::TooHigh);
+        assert!(too_high.rules_out("1000").is_some());
+        assert!(too_high.rules_out("1001").is_some());
+        assert!(too_high.rules_out("999").is_none());
+        assert!(too_high.rules_out("abc").is_none());
+    }
+
+    #[test]
+    fn rules_out_beyond_too_low() {
+        let too_low = submission("1000", Outcome::TooLow);
+        assert!(too_low.rules_out("1000").is_some());
+        assert!(too_low.rules_out("-5").is_some());
+        assert!(too_low.rules_out("1001").is_none());
+    }
+}
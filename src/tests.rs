@@ -0,0 +1,40 @@
+//! One test per part of every day and input that has an expected answer, generated by
+//! build.rs. Tests of real inputs that are not there are ignored.
+
+// nothing is tested when every year is disabled
+#![allow(dead_code)]
+
+use std::path::Path;
+
+use crate::helpers::{DayReport, RunConfig, Verdict};
+use crate::inputs::DayInput;
+
+fn check(year: u32, day: u32, part: u32, input: DayInput) {
+    let config = RunConfig {
+        bench: None,
+        part: Some(part),
+        timeout: None,
+    };
+    let report: DayReport = crate::run_input(year, day, input, &config)
+        .unwrap_or_else(|err| panic!("cannot run day {day}: {err}"))
+        .unwrap_or_else(|| panic!("no solution for day {day}"));
+    let verdict = match part {
+        1 => report.part1.as_ref(),
+        _ => report.part2.as_ref(),
+    }
+    .map(|result| &result.verdict);
+    assert_eq!(verdict, Some(&Verdict::Correct), "\n{report}");
+}
+
+fn check_real(year: u32, day: u32, part: u32) {
+    let input = DayInput::real(year, day).unwrap_or_else(|err| panic!("{err}"));
+    check(year, day, part, input);
+}
+
+fn check_example(year: u32, day: u32, part: u32, path: &str, label: &str) {
+    let input = DayInput::example(Path::new(path), label.to_owned(), year, day)
+        .unwrap_or_else(|err| panic!("{err}"));
+    check(year, day, part, input);
+}
+
+include!(concat!(env!("OUT_DIR"), "/tests.rs"));
@@ -1,4 +1,8 @@
-use std::fmt::Display;
+use std::fmt::{self, Display};
+
+use serde::de::DeserializeOwned;
+
+use crate::parse::ParseError;
 
 pub mod days {
     #![allow(non_upper_case_globals, dead_code)]
@@ -36,8 +40,53 @@ pub const Part2: u32 = 2;
 
 pub trait ParseInput<const D: u32> {
     type Parsed;
+    /// Puzzle values that are not part of the input text and differ between the examples
+    /// and the real input, e.g. a number of steps. `Default` gives the real input ones,
+    /// examples set theirs in the `params` of their answers file.
+    type Params: DeserializeOwned + Default + Send + Sync + 'static = ();
 
     fn parse_input(input: &str) -> Self::Parsed;
+
+    /// What the runner parses the input with
+    fn try_parse_input(input: &str, _params: &Self::Params) -> Result<Self::Parsed, ParseError> {
+        Ok(Self::parse_input(input))
+    }
+}
+
+/// Parsing of the days that have params, or that report malformed input as a
+/// `ParseError` rather than a panic. They get `ParseInput` from it, whose `parse_input`
+/// panics on such errors.
+pub trait TryParseInput<const D: u32> {
+    type Parsed;
+    /// See `ParseInput::Params`
+    type Params: DeserializeOwned + Default + Send + Sync + 'static = ();
+
+    fn try_parse_input(input: &str, params: &Self::Params) -> Result<Self::Parsed, ParseError>;
+}
+
+impl<T: TryParseInput<D>, const D: u32> ParseInput<D> for T {
+    type Parsed = <T as TryParseInput<D>>::Parsed;
+    type Params = <T as TryParseInput<D>>::Params;
+
+    fn parse_input(input: &str) -> Self::Parsed {
+        <T as TryParseInput<D>>::try_parse_input(input, &Default::default())
+            .unwrap_or_else(|err| panic!("invalid input at {err}"))
+    }
+
+    fn try_parse_input(input: &str, params: &Self::Params) -> Result<Self::Parsed, ParseError> {
+        <T as TryParseInput<D>>::try_parse_input(input, params)
+    }
+}
+
+/// Output of a part without an answer, e.g. the second part of day 25. Such parts are not
+/// run and show as "n/a".
+#[derive(Debug, Clone, Copy)]
+pub struct NoAnswer;
+
+impl Display for NoAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "n/a")
+    }
 }
 
 pub trait Solution<const D: u32>: ParseInput<D> {
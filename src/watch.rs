@@ -0,0 +1,341 @@
+use std::os::unix::process::CommandExt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use aoc::helpers::{DayReport, RunConfig};
+use aoc::{panics, run_day, YEARS};
+use inotify::{Inotify, WatchDescriptor, WatchMask};
+use serde::{Deserialize, Serialize};
+
+/// Answers of the previous run, handed over to the rebuilt binary
+const PREVIOUS_VAR: &str = "AOC_WATCH_PREVIOUS";
+
+/// Editors write a file in several steps, wait this long for the last one
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Output of a part in a run, to compare with the next one
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Answer {
+    input: Option<String>,
+    part: u32,
+    output: String,
+    verdict: String,
+}
+
+impl Answer {
+    fn name(&self) -> String {
+        match &self.input {
+            Some(input) => format!("{input}, part {}", self.part),
+            None => format!("part {}", self.part),
+        }
+    }
+
+    fn describe(&self) -> String {
+        if self.output.is_empty() {
+            self.verdict.clone()
+        } else {
+            format!("{} [{}]", self.output, self.verdict)
+        }
+    }
+}
+
+fn answers(reports: &[DayReport]) -> Vec<Answer> {
+    reports
+        .iter()
+        .flat_map(|report| {
+            [&report.part1, &report.part2]
+                .into_iter()
+                .flatten()
+                .map(|result| Answer {
+                    input: report.input.clone(),
+                    part: result.part,
+                    output: result.output.clone(),
+                    verdict: result.verdict.to_string(),
+                })
+        })
+        .collect()
+}
+
+fn print_diff(previous: &[Answer], current: &[Answer]) {
+    let mut changes = Vec::new();
+    for answer in current {
+        let before = previous
+            .iter()
+            .find(|p| (&p.input, p.part) == (&answer.input, answer.part));
+        match before {
+            Some(before) if before == answer => {}
+            Some(before) => changes.push(format!(
+                "{}: {} -> {}",
+                answer.name(),
+                before.describe(),
+                answer.describe()
+            )),
+            None => changes.push(format!("{}: new, {}", answer.name(), answer.describe())),
+        }
+    }
+    for before in previous {
+        if !current
+            .iter()
+            .any(|a| (&a.input, a.part) == (&before.input, before.part))
+        {
+            changes.push(format!("{}: gone", before.name()));
+        }
+    }
+
+    if changes.is_empty() {
+        println!("\nNo change since the previous run");
+    } else {
+        println!("\nChanges since the previous run:");
+        for change in changes {
+            println!("  {change}");
+        }
+    }
+}
+
+fn run(year: u32, day: u32, test: bool, config: &RunConfig) -> Vec<Answer> {
+    match panics::catch(|| run_day(year, day, test, config)) {
+        Ok(Ok(Some(reports))) => {
+            for report in &reports {
+                println!("{report}");
+            }
+            answers(&reports)
+        }
+        Ok(Ok(None)) => {
+            println!("No solution for day {day}");
+            Vec::new()
+        }
+        Ok(Err(err)) => {
+            println!("Error loading day: {err}");
+            Vec::new()
+        }
+        Err(panic) => {
+            println!("Day {day} panicked: {panic}");
+            Vec::new()
+        }
+    }
+}
+
+enum Change {
+    Input(String),
+    Source(String),
+}
+
+/// Whether the file `name` has one of `extensions`
+fn has_extension(name: &str, extensions: &[&str]) -> bool {
+    name.rsplit_once('.')
+        .is_some_and(|(_, extension)| extensions.contains(&extension))
+}
+
+/// Whether `name` is a file of `day` with one of `extensions`, like `day8.txt` or
+/// `day8_test2.json` for `day8`
+fn is_day_file(name: &str, day: u32, extensions: &[&str]) -> bool {
+    let Some((stem, extension)) = name
+        .strip_prefix(&format!("day{day}"))
+        .and_then(|rest| rest.rsplit_once('.'))
+    else {
+        return false;
+    };
+    (stem.is_empty() || stem.starts_with('_')) && extensions.contains(&extension)
+}
+
+struct Watcher {
+    inotify: Inotify,
+    inputs: WatchDescriptor,
+    /// The `day{N}_test/` directory of examples, if there is one
+    examples: Option<WatchDescriptor>,
+    sources: WatchDescriptor,
+    day: u32,
+}
+
+impl Watcher {
+    fn new(
+        inputs: &Path,
+        examples: &Path,
+        sources: &Path,
+        day: u32,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let inotify = Inotify::init().map_err(|err| format!("cannot start inotify: {err}"))?;
+        // editors either write the file in place or rename a new one over it
+        let mask = WatchMask::CLOSE_WRITE | WatchMask::MOVED_TO;
+        let watch = |dir: &Path| {
+            inotify
+                .watches()
+                .add(dir, mask)
+                .map_err(|err| format!("cannot watch {}: {err}", dir.display()))
+        };
+        Ok(Watcher {
+            inputs: watch(inputs)?,
+            examples: examples.is_dir().then(|| watch(examples)).transpose()?,
+            sources: watch(sources)?,
+            inotify,
+            day,
+        })
+    }
+
+    fn collect(&self, events: inotify::Events<'_>, changes: &mut Vec<Change>) {
+        for event in events {
+            let Some(name) = event.name.and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if event.wd == self.inputs && is_day_file(name, self.day, &["txt", "json"]) {
+                changes.push(Change::Input(name.to_owned()));
+            } else if Some(&event.wd) == self.examples.as_ref()
+                && has_extension(name, &["txt", "json"])
+            {
+                changes.push(Change::Input(format!("day{}_test/{name}", self.day)));
+            } else if event.wd == self.sources && name == format!("day{}.rs", self.day) {
+                changes.push(Change::Source(name.to_owned()));
+            }
+        }
+    }
+
+    /// Waits for a change to the files of the day. A change to the source wins over
+    /// changes to the inputs made at the same time.
+    fn wait(&mut self) -> Result<Change, Box<dyn std::error::Error>> {
+        let mut buffer = [0; 4096];
+        let mut changes = Vec::new();
+        while changes.is_empty() {
+            let events = self
+                .inotify
+                .read_events_blocking(&mut buffer)
+                .map_err(|err| format!("cannot read inotify events: {err}"))?;
+            self.collect(events, &mut changes);
+        }
+
+        std::thread::sleep(DEBOUNCE);
+        loop {
+            match self.inotify.read_events(&mut buffer) {
+                Ok(events) => self.collect(events, &mut changes),
+                Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => break,
+                Err(err) => return Err(format!("cannot read inotify events: {err}").into()),
+            }
+        }
+
+        let source = changes
+            .iter()
+            .position(|change| matches!(change, Change::Source(_)));
+        Ok(changes.swap_remove(source.unwrap_or(0)))
+    }
+}
+
+/// Builds the binary again, with the profile and features of the running one
+fn rebuild(exe: &Path) -> Result<bool, Box<dyn std::error::Error>> {
+    let profile = exe
+        .parent()
+        .and_then(|dir| dir.file_name())
+        .and_then(|name| name.to_str())
+        .unwrap_or("debug");
+    let mut cargo = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    cargo.arg("build");
+    match profile {
+        "debug" => {}
+        "release" => {
+            cargo.arg("--release");
+        }
+        profile => {
+            cargo.args(["--profile", profile]);
+        }
+    }
+    // the default features would bring back the years left out and drop the others
+    let mut features: Vec<String> = YEARS
+        .iter()
+        .map(|(year, _, _)| format!("aoc{year}"))
+        .collect();
+    if cfg!(feature = "alloc-stats") {
+        features.push("alloc-stats".to_owned());
+    }
+    cargo.args(["--no-default-features", "--features", &features.join(",")]);
+
+    let status = cargo
+        .status()
+        .map_err(|err| format!("cannot run cargo: {err}"))?;
+    Ok(status.success())
+}
+
+/// Runs a day, then runs it again whenever one of its inputs changes. When its source
+/// changes, the binary is rebuilt and replaces the running one. Each run shows how the
+/// answers changed since the previous one.
+pub fn watch(
+    year: u32,
+    day: u32,
+    test: bool,
+    config: &RunConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // once rebuilt, the running binary no longer has a path
+    let exe: PathBuf =
+        std::env::current_exe().map_err(|err| format!("cannot find the running binary: {err}"))?;
+    let inputs = PathBuf::from(format!("./inputs/{year}"));
+    let examples = inputs.join(format!("day{day}_test"));
+    let sources = PathBuf::from(format!("./src/aoc{year}"));
+    let mut watcher = Watcher::new(&inputs, &examples, &sources, day)?;
+
+    let mut previous = std::env::var(PREVIOUS_VAR)
+        .ok()
+        .and_then(|previous| serde_json::from_str::<Vec<Answer>>(&previous).ok());
+    let examples = if watcher.examples.is_some() {
+        format!(", {}/*", examples.display())
+    } else {
+        String::new()
+    };
+    println!(
+        "Watching {}/day{day}*{examples} and {}/day{day}.rs, press Ctrl-C to stop\n",
+        inputs.display(),
+        sources.display()
+    );
+
+    loop {
+        let current = run(year, day, test, config);
+        if let Some(previous) = &previous {
+            print_diff(previous, &current);
+        }
+        previous = Some(current);
+
+        loop {
+            match watcher.wait()? {
+                Change::Input(name) => {
+                    println!("\n=== {name} changed, running again ===\n");
+                    break;
+                }
+                Change::Source(name) => {
+                    println!("\n=== {name} changed, rebuilding ===\n");
+                    if !rebuild(&exe)? {
+                        println!("\nBuild failed, waiting for the next change");
+                        continue;
+                    }
+                    let previous = serde_json::to_string(previous.as_deref().unwrap_or_default())?;
+                    let err = Command::new(&exe)
+                        .args(std::env::args_os().skip(1))
+                        .env(PREVIOUS_VAR, previous)
+                        .exec();
+                    return Err(format!("cannot run the rebuilt binary: {err}").into());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_files() {
+        let inputs = ["txt", "json"];
+        assert!(is_day_file("day8.txt", 8, &inputs));
+        assert!(is_day_file("day8_test2.json", 8, &inputs));
+        assert!(!is_day_file("day18.txt", 8, &inputs));
+        assert!(!is_day_file("day8.rs", 8, &inputs));
+        assert!(!is_day_file("day8_test.txt.tmp", 8, &inputs));
+        assert!(!is_day_file("day8", 8, &inputs));
+        assert!(is_day_file("day8.rs", 8, &["rs"]));
+    }
+
+    #[test]
+    fn example_files() {
+        assert!(has_extension("steps10.txt", &["txt", "json"]));
+        assert!(has_extension("steps10.json", &["txt", "json"]));
+        assert!(!has_extension("steps10.txt~", &["txt", "json"]));
+        assert!(!has_extension("README", &["txt", "json"]));
+    }
+}
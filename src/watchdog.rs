@@ -0,0 +1,39 @@
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use crate::jobs::WORKER_STACK_SIZE;
+
+/// Runs `f` on a worker thread and waits at most `timeout` for its result.
+///
+/// Returns `None` if `f` did not finish in time. There is no way to stop a thread, so
+/// the worker is left running in the background until the process exits. Without a
+/// timeout, `f` simply runs on the calling thread.
+pub fn with_timeout<R, F>(timeout: Option<Duration>, f: F) -> Option<R>
+where
+    R: Send + 'static,
+    F: FnOnce() -> R + Send + 'static,
+{
+    let Some(timeout) = timeout else {
+        return Some(f());
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let handle = thread::Builder::new()
+        .stack_size(WORKER_STACK_SIZE)
+        .spawn(move || {
+            // the receiver is gone if we already timed out, nobody cares about the result
+            let _ = tx.send(f());
+        })
+        .expect("failed to spawn solution thread");
+
+    match rx.recv_timeout(timeout) {
+        Ok(res) => Some(res),
+        Err(RecvTimeoutError::Timeout) => None,
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            // forward the panic of the worker to the caller
+            Err(payload) => std::panic::resume_unwind(payload),
+            Ok(()) => unreachable!("worker thread exited without sending its result"),
+        },
+    }
+}
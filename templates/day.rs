@@ -4,10 +4,10 @@ use crate::traits::ParseInput;
 use crate::traits::Solution;
 
 impl ParseInput<Day1> for Aoc2019 {
-    type Parsed = _;
+    type Parsed = String;
 
     fn parse_input(input: &str) -> Self::Parsed {
-        todo!()
+        input.to_owned()
     }
 }
 
@@ -15,11 +15,11 @@ impl Solution<Day1> for Aoc2019 {
     type Part1Output = u32;
     type Part2Output = u32;
 
-    fn part1(input: &_) -> u32 {
+    fn part1(_input: &String) -> u32 {
         todo!()
     }
 
-    fn part2(input: &_) -> u32 {
+    fn part2(_input: &String) -> u32 {
         todo!()
     }
 }
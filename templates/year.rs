@@ -0,0 +1,8 @@
+use crate::helpers::{run, DayReport, RunConfig};
+use crate::inputs::DayInput;
+use crate::traits::days::*;
+
+pub struct Aoc2019;
+
+// `DAYS` and `run_solution_for_day`, generated by build.rs from the day files
+include!(concat!(env!("OUT_DIR"), "/aoc2019.rs"));